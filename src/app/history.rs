@@ -0,0 +1,65 @@
+use crate::app::{DisplayOptions, InitialCircles};
+
+// Everything an undo step brings back
+#[derive(Clone, PartialEq)]
+pub struct Snapshot {
+    pub initial_circles: InitialCircles,
+    pub display_options: DisplayOptions,
+}
+
+#[derive(Clone, Default)]
+pub struct History {
+    undo_stack: Vec<Snapshot>,
+    redo_stack: Vec<Snapshot>,
+    // Last state pushed or restored, edits are measured against it
+    current: Option<Snapshot>,
+}
+impl History {
+    const MAX_STEPS: usize = 200;
+
+    // Called once per frame. Nothing is recorded while the pointer is held, so a whole drag
+    // or slider move ends up as a single step once it is released.
+    pub fn record(&mut self, snapshot: Snapshot, is_interacting: bool) {
+        let Some(current) = &self.current else {
+            self.current = Some(snapshot);
+            return;
+        };
+        if is_interacting || *current == snapshot {
+            return;
+        }
+
+        if let Some(previous) = self.current.replace(snapshot) {
+            self.undo_stack.push(previous);
+        }
+        if self.undo_stack.len() > Self::MAX_STEPS {
+            self.undo_stack.remove(0);
+        }
+        self.redo_stack.clear();
+    }
+
+    pub fn undo(&mut self) -> Option<Snapshot> {
+        let previous = self.undo_stack.pop()?;
+        if let Some(current) = self.current.replace(previous.clone()) {
+            self.redo_stack.push(current);
+        }
+
+        Some(previous)
+    }
+
+    pub fn redo(&mut self) -> Option<Snapshot> {
+        let next = self.redo_stack.pop()?;
+        if let Some(current) = self.current.replace(next.clone()) {
+            self.undo_stack.push(current);
+        }
+
+        Some(next)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo_stack.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo_stack.is_empty()
+    }
+}
@@ -0,0 +1,74 @@
+use apollonius::models::{input::Input, vector::Vector};
+use egui::{Event, Key, Modifiers};
+
+use crate::app::MyApp;
+
+impl MyApp {
+    // Runs on the raw input, before egui would use Tab and the arrows to move the focus between
+    // widgets. Keys only act on the scene while no text field or other widget has the focus.
+    pub fn handle_keyboard_editing(&mut self, ctx: &egui::Context, raw_input: &mut egui::RawInput) {
+        if ctx.memory(|memory| memory.focused().is_some()) {
+            return;
+        }
+
+        raw_input.events.retain(|event| match event {
+            Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+                ..
+            } if !modifiers.command && !modifiers.alt => !self.handle_key(*key, *modifiers),
+            _ => true,
+        });
+    }
+
+    // True when the key was used up
+    fn handle_key(&mut self, key: Key, modifiers: Modifiers) -> bool {
+        let count = self.initial_circles.inputs.len();
+        if key == Key::Tab {
+            self.selected_input = Some(match (self.selected_input, modifiers.shift) {
+                (Some(idx), true) => (idx + count - 1) % count,
+                (Some(idx), false) => (idx + 1) % count,
+                (None, true) => count - 1,
+                (None, false) => 0,
+            });
+            return true;
+        }
+
+        let Some(idx) = self.selected_input else {
+            return false;
+        };
+        let nudge = self.keyboard_steps.nudge * if modifiers.shift { 10.0 } else { 1.0 };
+        // Plus is Shift and Equals on most layouts, so Shift cannot pick a larger radius step
+        // without + growing faster than - shrinks
+        let growth = self.keyboard_steps.radius;
+        // y points down on screen
+        let direction = match key {
+            Key::ArrowLeft => Vector::new(-1.0, 0.0),
+            Key::ArrowRight => Vector::new(1.0, 0.0),
+            Key::ArrowUp => Vector::new(0.0, -1.0),
+            Key::ArrowDown => Vector::new(0.0, 1.0),
+            _ => Vector::new(0.0, 0.0),
+        };
+
+        match key {
+            Key::Escape => self.selected_input = None,
+            Key::Delete => self.remove_input(idx),
+            Key::ArrowLeft | Key::ArrowRight | Key::ArrowUp | Key::ArrowDown => {
+                self.initial_circles
+                    .get_mut(idx)
+                    .translate(direction * nudge);
+            }
+            // Equals is + without Shift
+            Key::Plus | Key::Equals | Key::Minus => {
+                if let Input::Circle(circle) = self.initial_circles.get_mut(idx) {
+                    let change = if key == Key::Minus { -growth } else { growth };
+                    circle.radius = (circle.radius + change).max(0.0);
+                }
+            }
+            _ => return false,
+        }
+
+        true
+    }
+}
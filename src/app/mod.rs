@@ -0,0 +1,361 @@
+pub mod history;
+pub mod keyboard;
+pub mod scene_file;
+
+use std::{collections::HashMap, path::Path};
+
+use apollonius::models::{
+    circle::Circle, input::Input, locus::Locus, point::Point, solution_tracker::SolutionTracker,
+    tangency::TangencySignature, tolerance::Tolerance,
+};
+use egui::Rect;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{
+        history::{History, Snapshot},
+        scene_file::SceneFile,
+    },
+    enums::{construction_step::ConstructionStep, dragging::Dragging, theme_mode::ThemeMode},
+};
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct InitialCircles {
+    pub inputs: Vec<Input>,
+    // Indices of the three inputs fed to the solver
+    pub selected: [usize; 3],
+}
+impl InitialCircles {
+    pub const MIN_COUNT: usize = 3;
+
+    pub fn selected_inputs(&self) -> [Input; 3] {
+        self.selected.map(|idx| self.inputs[idx])
+    }
+
+    pub fn get_mut(&mut self, idx: usize) -> &mut Input {
+        &mut self.inputs[idx]
+    }
+
+    pub fn is_selected(&self, idx: usize) -> bool {
+        self.selected.contains(&idx)
+    }
+
+    // Picking an input already used by another slot swaps the two slots
+    pub fn select(&mut self, slot: usize, idx: usize) {
+        if let Some(other) = self.selected.iter().position(|selected| *selected == idx) {
+            self.selected.swap(slot, other);
+        } else {
+            self.selected[slot] = idx;
+        }
+    }
+
+    pub fn add(&mut self, input: Input) {
+        self.inputs.push(input);
+    }
+
+    // The solver always needs three inputs, a removed selected one is replaced by the first
+    // unselected input
+    pub fn remove(&mut self, idx: usize) {
+        if self.inputs.len() <= Self::MIN_COUNT {
+            return;
+        }
+
+        if let Some(slot) = self.selected.iter().position(|selected| *selected == idx) {
+            let replacement = (0..self.inputs.len())
+                .find(|candidate| !self.is_selected(*candidate))
+                .unwrap();
+            self.selected[slot] = replacement;
+        }
+        self.inputs.remove(idx);
+        for selected in self.selected.iter_mut() {
+            if *selected > idx {
+                *selected -= 1;
+            }
+        }
+    }
+
+    // Every combination of three inputs, in increasing index order
+    pub fn triples(&self) -> Vec<[usize; 3]> {
+        let count = self.inputs.len();
+        let mut triples = Vec::new();
+        for i in 0..count {
+            for j in i + 1..count {
+                for k in j + 1..count {
+                    triples.push([i, j, k]);
+                }
+            }
+        }
+
+        triples
+    }
+}
+impl Default for InitialCircles {
+    fn default() -> Self {
+        Self {
+            inputs: vec![
+                Input::Circle(Circle {
+                    center: Point { x: 50.0, y: 50.0 },
+                    radius: 35.0,
+                }),
+                Input::Circle(Circle {
+                    center: Point { x: -60.0, y: 10.0 },
+                    radius: 50.0,
+                }),
+                Input::Circle(Circle {
+                    center: Point { x: 40.0, y: -30.0 },
+                    radius: 20.0,
+                }),
+            ],
+            selected: [0, 1, 2],
+        }
+    }
+}
+
+// Locus of one tracked solution, drawn in the color of the branch it follows
+#[derive(Clone)]
+pub struct Trace {
+    pub pair_index: usize,
+    pub locus: Locus,
+}
+
+// Fields missing from older scene files take their default
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplayOptions {
+    pub show_homothetic: bool,
+    pub show_homothetic_axes: bool,
+    pub show_radical: bool,
+    pub show_inverse_poles: bool,
+    pub show_pole_lines: bool,
+    pub show_touch_points: bool,
+    pub show_signatures: [bool; TangencySignature::COUNT],
+    pub show_algebraic: bool,
+    // Also solve every other triple of inputs, drawn without the construction
+    pub show_every_triple: bool,
+}
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self {
+            show_homothetic: false,
+            show_homothetic_axes: false,
+            show_radical: true,
+            show_inverse_poles: false,
+            show_pole_lines: false,
+            show_touch_points: false,
+            show_signatures: [true; TangencySignature::COUNT],
+            show_algebraic: false,
+            show_every_triple: false,
+        }
+    }
+}
+impl DisplayOptions {
+    // In construction mode the step decides what is visible instead of the checkboxes
+    pub fn for_step(&self, step: Option<ConstructionStep>) -> Self {
+        let Some(step) = step else {
+            return *self;
+        };
+
+        Self {
+            show_homothetic: step >= ConstructionStep::HomotheticCenters,
+            show_homothetic_axes: step >= ConstructionStep::HomotheticAxes,
+            show_radical: step >= ConstructionStep::RadicalCenter,
+            show_inverse_poles: step >= ConstructionStep::InversePoles,
+            show_pole_lines: step >= ConstructionStep::PoleLines,
+            show_touch_points: step >= ConstructionStep::TouchPoints,
+            show_signatures: [step >= ConstructionStep::Solutions; TangencySignature::COUNT],
+            show_algebraic: false,
+            show_every_triple: false,
+        }
+    }
+}
+
+// Side panels opened from the top bar, left out of undo and scene files
+#[derive(Clone, Copy, Default)]
+pub struct Panels {
+    pub show_settings: bool,
+    pub show_traces: bool,
+    pub show_inputs: bool,
+}
+
+// How far one key press moves a center, ten times that with Shift, or changes a radius
+#[derive(Clone, Copy)]
+pub struct KeyboardSteps {
+    pub nudge: f64,
+    pub radius: f64,
+}
+impl Default for KeyboardSteps {
+    fn default() -> Self {
+        Self {
+            nudge: 1.0,
+            radius: 1.0,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct MyApp {
+    pub initial_circles: InitialCircles,
+    pub display_options: DisplayOptions,
+    pub panels: Panels,
+    pub scene_rect: egui::Rect,
+    pub is_dragging: Dragging,
+    pub theme_mode: ThemeMode,
+    pub tolerance: Tolerance,
+    // Some while stepping through the construction
+    pub construction_step: Option<ConstructionStep>,
+    pub solution_tracker: SolutionTracker,
+    // Traced solutions, by tracked solution id
+    pub traces: HashMap<usize, Trace>,
+    pub history: History,
+    // Scene file path typed in the menu, and how the last save or load went
+    pub scene_path: String,
+    pub scene_status: Option<String>,
+    // Input edited from the keyboard, kept after the drag that picked it ends
+    pub selected_input: Option<usize>,
+    pub keyboard_steps: KeyboardSteps,
+}
+impl MyApp {
+    fn reset() -> Self {
+        Self {
+            initial_circles: InitialCircles::default(),
+            display_options: DisplayOptions::default(),
+            panels: Panels::default(),
+            scene_rect: Rect::ZERO,
+            is_dragging: Dragging::None,
+            theme_mode: ThemeMode::Dark,
+            tolerance: Tolerance::default(),
+            construction_step: None,
+            solution_tracker: SolutionTracker::default(),
+            traces: HashMap::new(),
+            history: History::default(),
+            scene_path: String::from("scene.json"),
+            scene_status: None,
+            selected_input: None,
+            keyboard_steps: KeyboardSteps::default(),
+        }
+    }
+
+    pub fn reset_scene(&self) -> Self {
+        Self {
+            scene_rect: Rect::ZERO,
+            ..self.clone()
+        }
+    }
+    pub fn reset_circles(&self) -> Self {
+        Self {
+            initial_circles: InitialCircles::default(),
+            solution_tracker: SolutionTracker::default(),
+            traces: HashMap::new(),
+            selected_input: None,
+            ..self.clone()
+        }
+    }
+}
+impl MyApp {
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            initial_circles: self.initial_circles.clone(),
+            display_options: self.display_options,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.initial_circles = snapshot.initial_circles;
+        self.display_options = snapshot.display_options;
+        // The restored scene may have fewer inputs, and a drag going on would carry over to
+        // whatever now sits at its index
+        self.selected_input = self
+            .selected_input
+            .filter(|idx| *idx < self.initial_circles.inputs.len());
+        self.is_dragging = Dragging::None;
+    }
+
+    // Keeps the selection on the same input, or drops it with the input. A drag still going on
+    // would point at the wrong input, or past the last one, so it ends here.
+    pub fn remove_input(&mut self, idx: usize) {
+        if self.initial_circles.inputs.len() <= InitialCircles::MIN_COUNT {
+            return;
+        }
+
+        self.initial_circles.remove(idx);
+        self.is_dragging = Dragging::None;
+        self.selected_input = match self.selected_input {
+            Some(selected) if selected == idx => None,
+            Some(selected) if selected > idx => Some(selected - 1),
+            selected => selected,
+        };
+    }
+
+    pub fn undo(&mut self) {
+        if let Some(snapshot) = self.history.undo() {
+            self.restore(snapshot);
+        }
+    }
+
+    pub fn redo(&mut self) {
+        if let Some(snapshot) = self.history.redo() {
+            self.restore(snapshot);
+        }
+    }
+
+    // Both report how it went in the scene status instead of failing
+    pub fn save_scene(&mut self, path: &Path) {
+        self.scene_status = Some(match SceneFile::from_app(self).save(path) {
+            Ok(()) => format!("Saved {}", path.display()),
+            Err(error) => format!("Could not save {}: {error}", path.display()),
+        });
+    }
+
+    pub fn load_scene(&mut self, path: &Path) {
+        self.scene_status = Some(match SceneFile::load(path) {
+            Ok(scene) => {
+                self.apply_scene(scene);
+                format!("Loaded {}", path.display())
+            }
+            Err(error) => format!("Could not load {}: {error}", path.display()),
+        });
+    }
+
+    // State of the last session, unless it was saved by a newer version
+    pub fn from_storage(storage: Option<&dyn eframe::Storage>) -> Self {
+        let mut app = Self::default();
+        if let Some(scene) = storage
+            .and_then(|storage| eframe::get_value::<SceneFile>(storage, eframe::APP_KEY))
+            .filter(|scene| scene.validate().is_ok())
+        {
+            app.apply_scene(scene);
+        }
+
+        app
+    }
+
+    fn apply_scene(&mut self, scene: SceneFile) {
+        self.scene_rect = scene.camera_rect();
+        self.initial_circles = scene.initial_circles;
+        self.display_options = scene.display_options;
+        self.theme_mode = scene.theme_mode;
+        self.construction_step = None;
+        self.selected_input = None;
+        self.solution_tracker.reset();
+        self.traces.clear();
+    }
+
+    // Ctrl+Shift+Z has to be checked first, Ctrl+Z would consume it as well
+    pub fn handle_history_shortcuts(&mut self, ctx: &egui::Context) {
+        let redo_shortcut = egui::KeyboardShortcut::new(
+            egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+            egui::Key::Z,
+        );
+        let undo_shortcut = egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::Z);
+        if ctx.input_mut(|i| i.consume_shortcut(&redo_shortcut)) {
+            self.redo();
+        } else if ctx.input_mut(|i| i.consume_shortcut(&undo_shortcut)) {
+            self.undo();
+        }
+    }
+}
+impl Default for MyApp {
+    fn default() -> Self {
+        Self::reset()
+    }
+}
@@ -0,0 +1,97 @@
+use std::{fmt, fs, io, path::Path};
+
+use egui::Rect;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    app::{DisplayOptions, InitialCircles, MyApp},
+    enums::theme_mode::ThemeMode,
+};
+
+// Bumped whenever a saved field changes meaning, older files are still read
+pub const SCENE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+pub struct SceneFile {
+    pub version: u32,
+    pub initial_circles: InitialCircles,
+    pub display_options: DisplayOptions,
+    // Visible part of the scene as min x, min y, max x, max y
+    pub camera: [f32; 4],
+    pub theme_mode: ThemeMode,
+}
+impl SceneFile {
+    pub fn from_app(app: &MyApp) -> Self {
+        let rect = app.scene_rect;
+        Self {
+            version: SCENE_VERSION,
+            initial_circles: app.initial_circles.clone(),
+            display_options: app.display_options,
+            camera: [rect.min.x, rect.min.y, rect.max.x, rect.max.y],
+            theme_mode: app.theme_mode,
+        }
+    }
+
+    pub fn camera_rect(&self) -> Rect {
+        let [min_x, min_y, max_x, max_y] = self.camera;
+        Rect::from_min_max(egui::pos2(min_x, min_y), egui::pos2(max_x, max_y))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), SceneError> {
+        let json = serde_json::to_string_pretty(self).map_err(SceneError::Format)?;
+        fs::write(path, json).map_err(SceneError::Io)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, SceneError> {
+        let json = fs::read_to_string(path).map_err(SceneError::Io)?;
+        let scene: Self = serde_json::from_str(&json).map_err(SceneError::Format)?;
+        scene.validate()?;
+
+        Ok(scene)
+    }
+
+    // The solver indexes the inputs through the selection, so it has to point at three of them
+    pub fn validate(&self) -> Result<(), SceneError> {
+        if self.version > SCENE_VERSION {
+            return Err(SceneError::UnsupportedVersion(self.version));
+        }
+
+        let circles = &self.initial_circles;
+        let [a, b, c] = circles.selected;
+        if circles.inputs.len() < InitialCircles::MIN_COUNT {
+            Err(SceneError::Invalid("fewer than three inputs"))
+        } else if circles
+            .selected
+            .iter()
+            .any(|idx| *idx >= circles.inputs.len())
+        {
+            Err(SceneError::Invalid("selected input out of range"))
+        } else if a == b || b == c || c == a {
+            Err(SceneError::Invalid("same input selected twice"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SceneError {
+    Io(io::Error),
+    Format(serde_json::Error),
+    UnsupportedVersion(u32),
+    Invalid(&'static str),
+}
+impl fmt::Display for SceneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneError::Io(error) => write!(f, "{error}"),
+            SceneError::Format(error) => write!(f, "not a scene file, {error}"),
+            SceneError::UnsupportedVersion(version) => write!(
+                f,
+                "scene version {version} is newer than the supported {SCENE_VERSION}"
+            ),
+            SceneError::Invalid(reason) => write!(f, "invalid scene, {reason}"),
+        }
+    }
+}
+impl std::error::Error for SceneError {}
@@ -0,0 +1,96 @@
+use std::path::PathBuf;
+
+use apollonius::Tolerance;
+
+pub const USAGE: &str = "\
+Usage: apollonius-cli [OPTIONS] [INPUT INPUT INPUT]
+
+Prints every circle tangent to three inputs. Each INPUT is a circle as x,y,r or a line
+through two points as x1,y1,x2,y2. Without inputs they are read from --file, or from stdin.
+
+Options:
+  -f, --file PATH       Read the inputs from a CSV or JSON file
+  -o, --format FORMAT   Output as human (default), csv or json
+      --absolute TOL    Absolute tolerance, defaults to 1e-6
+      --relative TOL    Relative tolerance, defaults to 1e-9
+  -h, --help            Print this help
+
+CSV holds one input per line, JSON an array of three inputs as stored in scene files.
+Exits with 1 on bad arguments or unreadable inputs and 2 on degenerate inputs.";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Human,
+    Csv,
+    Json,
+}
+
+// Where the three inputs come from
+pub enum Source {
+    Arguments(Vec<String>),
+    File(PathBuf),
+    Stdin,
+}
+
+pub struct Args {
+    pub source: Source,
+    pub format: Format,
+    pub tolerance: Tolerance,
+    pub help: bool,
+}
+impl Args {
+    pub fn parse(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut file: Option<PathBuf> = None;
+        let mut format = Format::Human;
+        let mut tolerance = Tolerance::default();
+        let mut help = false;
+        let mut inputs: Vec<String> = Vec::new();
+
+        let mut args = args.peekable();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or_else(|| format!("{name} needs a value"));
+            match arg.as_str() {
+                "-h" | "--help" => help = true,
+                "-f" | "--file" => file = Some(PathBuf::from(value(&arg)?)),
+                "-o" | "--format" => {
+                    format = match value(&arg)?.as_str() {
+                        "human" => Format::Human,
+                        "csv" => Format::Csv,
+                        "json" => Format::Json,
+                        other => return Err(format!("unknown format {other}")),
+                    }
+                }
+                "--absolute" => tolerance.absolute = parse_tolerance(&value(&arg)?)?,
+                "--relative" => tolerance.relative = parse_tolerance(&value(&arg)?)?,
+                // Negative coordinates look like options, so anything numeric is an input
+                _ if arg.starts_with('-')
+                    && !arg[1..].starts_with(|c: char| c.is_ascii_digit() || c == '.') =>
+                {
+                    return Err(format!("unknown option {arg}"));
+                }
+                _ => inputs.push(arg),
+            }
+        }
+
+        let source = match (file, inputs.is_empty()) {
+            (Some(_), false) => return Err(String::from("give either inputs or --file")),
+            (Some(path), true) => Source::File(path),
+            (None, false) => Source::Arguments(inputs),
+            (None, true) => Source::Stdin,
+        };
+
+        Ok(Self {
+            source,
+            format,
+            tolerance,
+            help,
+        })
+    }
+}
+
+fn parse_tolerance(value: &str) -> Result<f64, String> {
+    match value.parse::<f64>() {
+        Ok(tolerance) if tolerance >= 0.0 && tolerance.is_finite() => Ok(tolerance),
+        _ => Err(format!("invalid tolerance {value}")),
+    }
+}
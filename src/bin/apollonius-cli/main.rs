@@ -0,0 +1,133 @@
+use std::{io::Write, process::ExitCode};
+
+use apollonius::{
+    Configuration, ConfigurationClass, GeometryError, Input, Tolerance, models::segment::Segment,
+    services::calc,
+};
+
+use crate::args::{Args, USAGE};
+
+mod args;
+mod read;
+mod report;
+
+const EXIT_USAGE: u8 = 1;
+const EXIT_DEGENERATE: u8 = 2;
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(error) => return fail(&error, EXIT_USAGE),
+    };
+    if args.help {
+        println!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+
+    let inputs: [Input; 3] = match read::read_inputs(&args.source).map(<[Input; 3]>::try_from) {
+        Ok(Ok(inputs)) => inputs,
+        Ok(Err(inputs)) => {
+            return fail(
+                &format!("expected 3 inputs, got {}", inputs.len()),
+                EXIT_USAGE,
+            );
+        }
+        Err(error) => return fail(&error, EXIT_USAGE),
+    };
+    if let Err(error) = check_inputs(&inputs, &args.tolerance) {
+        return fail(&error, EXIT_DEGENERATE);
+    }
+
+    // Same pipeline as the central panel
+    let solutions = apollonius::solve(&inputs, &args.tolerance);
+    // A closed pipe, as with `| head`, is not worth a panic
+    let _ = writeln!(
+        std::io::stdout(),
+        "{}",
+        report::format_report(&solutions, &args.tolerance, args.format)
+    );
+    ExitCode::SUCCESS
+}
+
+// Inputs the solver has no meaningful answer for
+fn check_inputs(inputs: &[Input; 3], tolerance: &Tolerance) -> Result<(), String> {
+    for (idx, input) in inputs.iter().enumerate() {
+        match input {
+            Input::Circle(circle) if circle.radius < 0.0 => {
+                return Err(format!("input {}: negative radius", idx + 1));
+            }
+            Input::Line(segment) if tolerance.is_same_point(segment.0, segment.1) => {
+                return Err(format!(
+                    "input {}: {}",
+                    idx + 1,
+                    GeometryError::DegenerateLine
+                ));
+            }
+            _ => {}
+        }
+    }
+    for (i, j) in [(0, 1), (1, 2), (0, 2)] {
+        if is_same_input(&inputs[i], &inputs[j], tolerance) {
+            return Err(format!(
+                "inputs {} and {}: {}",
+                i + 1,
+                j + 1,
+                GeometryError::CoincidentCircles
+            ));
+        }
+    }
+    if let [Input::Line(s1), Input::Line(s2), Input::Line(s3)] = inputs {
+        check_lines([s1, s2, s3], tolerance).map_err(|error| format!("inputs 1 to 3: {error}"))?;
+    }
+    if Configuration::classify(inputs, tolerance).class == ConfigurationClass::Coincident {
+        return Err(format!("inputs: {}", GeometryError::CoincidentCircles));
+    }
+
+    Ok(())
+}
+
+// Three parallel lines have no circle touching them all, three lines through one point only
+// that point
+fn check_lines(segments: [&Segment; 3], tolerance: &Tolerance) -> Result<(), GeometryError> {
+    let crossing = [(0, 1, 2), (1, 2, 0), (2, 0, 1)]
+        .into_iter()
+        .find_map(|(i, j, k)| {
+            calc::find_intersection(
+                &segments[i].as_straight_line(),
+                &segments[j].as_straight_line(),
+                tolerance,
+            )
+            .ok()
+            .map(|point| (point, k))
+        });
+    match crossing {
+        None => Err(GeometryError::ParallelLines),
+        Some((point, k))
+            if tolerance.is_zero(
+                segments[k].signed_distance(point),
+                point.to_vector().length(),
+            ) =>
+        {
+            Err(GeometryError::ConcurrentLines)
+        }
+        Some(_) => Ok(()),
+    }
+}
+
+fn is_same_input(a: &Input, b: &Input, tolerance: &Tolerance) -> bool {
+    match (a, b) {
+        (Input::Circle(c1), Input::Circle(c2)) => {
+            tolerance.is_same_point(c1.center, c2.center)
+                && tolerance.is_equal(c1.radius, c2.radius)
+        }
+        (Input::Line(s1), Input::Line(s2)) => [s2.0, s2.1]
+            .iter()
+            .all(|point| tolerance.is_zero(s1.signed_distance(*point), s1.0.distance(s1.1))),
+        _ => false,
+    }
+}
+
+fn fail(message: &str, code: u8) -> ExitCode {
+    eprintln!("apollonius-cli: {message}");
+    ExitCode::from(code)
+}
@@ -0,0 +1,87 @@
+use std::{fs, io::Read, path::Path};
+
+use apollonius::{
+    Circle, Input,
+    models::{point::Point, segment::Segment},
+};
+
+use crate::args::Source;
+
+pub fn read_inputs(source: &Source) -> Result<Vec<Input>, String> {
+    match source {
+        Source::Arguments(inputs) => inputs.iter().map(|input| parse_input(input)).collect(),
+        Source::File(path) => {
+            let text = fs::read_to_string(path)
+                .map_err(|error| format!("could not read {}: {error}", path.display()))?;
+            if is_json_path(path) {
+                parse_json(&text)
+            } else {
+                parse_csv(&text)
+            }
+        }
+        Source::Stdin => {
+            let mut text = String::new();
+            std::io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|error| format!("could not read stdin: {error}"))?;
+            // Anything starting as a JSON array is taken for JSON
+            if text.trim_start().starts_with('[') {
+                parse_json(&text)
+            } else {
+                parse_csv(&text)
+            }
+        }
+    }
+}
+
+fn is_json_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"))
+}
+
+fn parse_json(text: &str) -> Result<Vec<Input>, String> {
+    serde_json::from_str(text).map_err(|error| format!("invalid JSON: {error}"))
+}
+
+// Blank lines, # comments and a header line without numbers are skipped
+fn parse_csv(text: &str) -> Result<Vec<Input>, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .enumerate()
+        .filter(|(idx, line)| {
+            *idx > 0
+                || line
+                    .split(',')
+                    .any(|field| field.trim().parse::<f64>().is_ok())
+        })
+        .map(|(_, line)| parse_input(line))
+        .collect()
+}
+
+// x,y,r for a circle, x1,y1,x2,y2 for a line
+fn parse_input(text: &str) -> Result<Input, String> {
+    let values: Vec<f64> = text
+        .split(',')
+        .map(|field| {
+            field
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .ok_or_else(|| format!("invalid number {:?} in {text:?}", field.trim()))
+        })
+        .collect::<Result<_, _>>()?;
+
+    match values[..] {
+        [x, y, radius] => Ok(Input::Circle(Circle {
+            center: Point { x, y },
+            radius,
+        })),
+        [x1, y1, x2, y2] => Ok(Input::Line(Segment(
+            Point { x: x1, y: y1 },
+            Point { x: x2, y: y2 },
+        ))),
+        _ => Err(format!("expected x,y,r or x1,y1,x2,y2, got {text:?}")),
+    }
+}
@@ -0,0 +1,96 @@
+use apollonius::{Solutions, Tolerance};
+use serde::Serialize;
+
+use crate::args::Format;
+
+#[derive(Serialize)]
+struct SolutionRecord {
+    signature: String,
+    x: f64,
+    y: f64,
+    radius: f64,
+    residual: f64,
+    tangent: bool,
+}
+
+#[derive(Serialize)]
+struct Report {
+    configuration: String,
+    method: String,
+    expected_count: Option<usize>,
+    solutions: Vec<SolutionRecord>,
+}
+impl Report {
+    fn new(solutions: &Solutions, tolerance: &Tolerance) -> Self {
+        Self {
+            configuration: solutions.configuration.class.value(),
+            method: solutions.method.value(),
+            expected_count: solutions.configuration.expected_count(),
+            solutions: solutions
+                .tangent_circles
+                .iter()
+                .map(|solution| SolutionRecord {
+                    signature: solution.signature.label(),
+                    x: solution.circle.center.x,
+                    y: solution.circle.center.y,
+                    radius: solution.circle.radius,
+                    residual: solution.residual,
+                    tangent: solution.is_tangent(tolerance),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub fn format_report(solutions: &Solutions, tolerance: &Tolerance, format: Format) -> String {
+    let report = Report::new(solutions, tolerance);
+    match format {
+        Format::Human => format_human(&report),
+        Format::Csv => std::iter::once(String::from("signature,x,y,radius,residual,tangent"))
+            .chain(report.solutions.iter().map(|solution| {
+                format!(
+                    "{},{},{},{},{:e},{}",
+                    solution.signature,
+                    solution.x,
+                    solution.y,
+                    solution.radius,
+                    solution.residual,
+                    solution.tangent
+                )
+            }))
+            .collect::<Vec<String>>()
+            .join("\n"),
+        // Only plain strings, numbers and bools, serializing cannot fail
+        Format::Json => serde_json::to_string_pretty(&report).unwrap(),
+    }
+}
+
+fn format_human(report: &Report) -> String {
+    let found = report.solutions.len();
+    let count = match report.expected_count {
+        Some(expected) => format!("{found} of {expected} solutions"),
+        None => format!("{found} solutions"),
+    };
+    let mut lines = vec![format!(
+        "{}: {count} by {}",
+        report.configuration,
+        report.method.to_lowercase()
+    )];
+    for solution in &report.solutions {
+        lines.push(format!(
+            "{}  center ({:.6}, {:.6})  radius {:.6}  residual {:.1e}{}",
+            solution.signature,
+            solution.x,
+            solution.y,
+            solution.radius,
+            solution.residual,
+            if solution.tangent {
+                ""
+            } else {
+                "  not tangent"
+            }
+        ));
+    }
+
+    lines.join("\n")
+}
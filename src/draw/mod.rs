@@ -0,0 +1,338 @@
+use apollonius::models::{
+    circle::Circle, geometry_error::GeometryError, homothetic_set::HomotheticSet, input::Input,
+    inverse_pole_set::InversePoleSet, locus::Locus, point::Point, segment::Segment,
+    tolerance::Tolerance, vector::Vector,
+};
+use egui::{Color32, Pos2, epaint::CircleShape};
+
+// Radius used to paint and grab point inputs and line handles, whose actual radius is zero
+pub const POINT_RADIUS: f64 = 4.0;
+
+// The geometry is computed in f64, egui only gets the f32 values when painting
+pub fn to_pos2(point: Point) -> Pos2 {
+    Pos2 {
+        x: point.x as f32,
+        y: point.y as f32,
+    }
+}
+
+pub fn to_point(pos: Pos2) -> Point {
+    Point {
+        x: pos.x as f64,
+        y: pos.y as f64,
+    }
+}
+
+pub fn to_vector(vec: egui::Vec2) -> Vector {
+    Vector {
+        x: vec.x as f64,
+        y: vec.y as f64,
+    }
+}
+
+pub fn get_input_clipping_rect(input: &Input) -> egui::Rect {
+    match input {
+        // Room around the boundary to grab the rim from outside
+        Input::Circle(circle) => egui::Rect::from_center_size(
+            to_pos2(circle.center),
+            egui::Vec2::splat(2.0 * (circle.radius + POINT_RADIUS) as f32),
+        ),
+        Input::Line(segment) => egui::Rect::from_two_pos(to_pos2(segment.0), to_pos2(segment.1))
+            .expand(POINT_RADIUS as f32),
+    }
+}
+
+// Endpoints of the infinite line through the segment, cut to the given rect
+pub fn get_line_across_rect(segment: &Segment, rect: egui::Rect) -> Option<[Pos2; 2]> {
+    let origin = to_pos2(segment.0);
+    let direction = to_pos2(segment.1) - origin;
+    if direction.length() < f32::EPSILON {
+        return None;
+    }
+
+    let mut t_min = f32::NEG_INFINITY;
+    let mut t_max = f32::INFINITY;
+    for (o, d, min, max) in [
+        (origin.x, direction.x, rect.min.x, rect.max.x),
+        (origin.y, direction.y, rect.min.y, rect.max.y),
+    ] {
+        if d.abs() < f32::EPSILON {
+            if o < min || o > max {
+                return None;
+            }
+            continue;
+        }
+        let (t0, t1) = ((min - o) / d, (max - o) / d);
+        t_min = t_min.max(t0.min(t1));
+        t_max = t_max.min(t0.max(t1));
+    }
+    if t_min > t_max {
+        return None;
+    }
+
+    Some([origin + direction * t_min, origin + direction * t_max])
+}
+
+pub fn draw_circle(
+    ui: &mut egui::Ui,
+    center: Pos2,
+    radius: f32,
+    fill: Color32,
+    stroke: egui::Stroke,
+) {
+    ui.painter().add(egui::Shape::Circle(CircleShape {
+        center,
+        radius,
+        fill,
+        stroke,
+    }));
+}
+
+pub fn draw_line(ui: &mut egui::Ui, points: [Pos2; 2], stroke: egui::Stroke) {
+    ui.painter()
+        .add(egui::Shape::LineSegment { points, stroke });
+}
+
+// Lines are drawn across the whole visible part of the scene, not only between their points
+pub fn draw_extended_line(ui: &mut egui::Ui, segment: &Segment, stroke: egui::Stroke) {
+    if let Some(points) = get_line_across_rect(segment, ui.clip_rect()) {
+        draw_line(ui, points, stroke);
+    }
+}
+
+pub fn draw_inputs(ui: &mut egui::Ui, inputs: &[Input], fill: Color32, tolerance: &Tolerance) {
+    for input in inputs {
+        match input {
+            Input::Circle(c) if c.is_point(tolerance) => draw_circle(
+                ui,
+                to_pos2(c.center),
+                POINT_RADIUS as f32,
+                fill.gamma_multiply(3.0),
+                egui::Stroke::NONE,
+            ),
+            Input::Circle(c) => draw_circle(
+                ui,
+                to_pos2(c.center),
+                c.radius as f32,
+                fill,
+                egui::Stroke::NONE,
+            ),
+            Input::Line(segment) => {
+                draw_extended_line(
+                    ui,
+                    segment,
+                    egui::Stroke::new(1.5, fill.gamma_multiply(3.0)),
+                );
+                for handle in [segment.0, segment.1] {
+                    draw_circle(
+                        ui,
+                        to_pos2(handle),
+                        POINT_RADIUS as f32,
+                        fill.gamma_multiply(3.0),
+                        egui::Stroke::NONE,
+                    );
+                }
+            }
+        }
+    }
+}
+
+// Outline around the input the keyboard edits
+pub fn draw_selected_input(
+    ui: &mut egui::Ui,
+    input: &Input,
+    stroke: Color32,
+    tolerance: &Tolerance,
+) {
+    let stroke = egui::Stroke::new(2.0, stroke);
+    match input {
+        Input::Circle(c) => draw_circle(
+            ui,
+            to_pos2(c.center),
+            if c.is_point(tolerance) {
+                2.0 * POINT_RADIUS as f32
+            } else {
+                c.radius as f32
+            },
+            Color32::TRANSPARENT,
+            stroke,
+        ),
+        Input::Line(segment) => {
+            draw_line(ui, [to_pos2(segment.0), to_pos2(segment.1)], stroke);
+            for handle in [segment.0, segment.1] {
+                draw_circle(
+                    ui,
+                    to_pos2(handle),
+                    2.0 * POINT_RADIUS as f32,
+                    Color32::TRANSPARENT,
+                    stroke,
+                );
+            }
+        }
+    }
+}
+
+pub fn draw_homothetic_centers(
+    ui: &mut egui::Ui,
+    homothetic_set: &HomotheticSet,
+    condition: bool,
+    fill: Color32,
+) {
+    if !condition {
+        return;
+    }
+    for pair in homothetic_set.pairs.clone().into_iter() {
+        if let Some(external) = pair.ex {
+            draw_circle(ui, to_pos2(external), 2.0, fill, egui::Stroke::NONE);
+        }
+        if let Some(internal) = pair.ir {
+            draw_circle(ui, to_pos2(internal), 2.0, fill, egui::Stroke::NONE);
+        }
+    }
+}
+
+pub fn draw_homothetic_axis(
+    ui: &mut egui::Ui,
+    axis: &Option<Segment>,
+    condition: bool,
+    stroke: Color32,
+) {
+    if !condition {
+        return;
+    }
+    if let Some(axis) = axis {
+        draw_extended_line(ui, axis, egui::Stroke::new(0.5, stroke));
+    }
+}
+
+pub fn draw_radical_center(
+    ui: &mut egui::Ui,
+    radical_center: &Result<Point, GeometryError>,
+    condition: bool,
+    fill: Color32,
+) {
+    if !condition {
+        return;
+    }
+    if let Ok(radical_center) = radical_center {
+        draw_circle(ui, to_pos2(*radical_center), 4.0, fill, egui::Stroke::NONE);
+    }
+}
+
+pub fn draw_inverse_poles(
+    ui: &mut egui::Ui,
+    poles_set: &Result<InversePoleSet, GeometryError>,
+    condition: bool,
+    fill: egui::Color32,
+) {
+    if !condition {
+        return;
+    }
+
+    if let Ok(set) = poles_set {
+        for pair in set.point_segment_pairs.clone() {
+            draw_circle(ui, to_pos2(pair.point), 2.0, fill, egui::Stroke::NONE);
+        }
+    }
+}
+
+// Lines joining the radical center to each pole of the set
+pub fn draw_pole_lines(
+    ui: &mut egui::Ui,
+    poles_set: &Result<InversePoleSet, GeometryError>,
+    radical_center: &Result<Point, GeometryError>,
+    condition: bool,
+    stroke: Color32,
+) {
+    if !condition {
+        return;
+    }
+
+    if let (Ok(set), Ok(radical_center)) = (poles_set, radical_center) {
+        for pair in set.point_segment_pairs.iter() {
+            draw_extended_line(
+                ui,
+                &Segment(pair.point, *radical_center),
+                egui::Stroke::new(0.25, stroke),
+            );
+        }
+    }
+}
+
+// The chords from the radical center through each pole, their ends are the touch points
+pub fn draw_touch_points(
+    ui: &mut egui::Ui,
+    poles_set: &Result<InversePoleSet, GeometryError>,
+    condition: bool,
+    fill: egui::Color32,
+) {
+    if !condition {
+        return;
+    }
+
+    if let Ok(set) = poles_set {
+        for pair in set.point_segment_pairs.clone() {
+            if let Ok(segment) = pair.segment {
+                draw_line(
+                    ui,
+                    [to_pos2(segment.0), to_pos2(segment.1)],
+                    egui::Stroke {
+                        width: 0.5,
+                        color: fill,
+                    },
+                );
+            }
+        }
+    }
+}
+
+pub fn draw_algebraic_circles(
+    ui: &mut egui::Ui,
+    circles: &[Circle],
+    condition: bool,
+    stroke: egui::Color32,
+) {
+    if !condition {
+        return;
+    }
+
+    for c in circles {
+        draw_circle(
+            ui,
+            to_pos2(c.center),
+            c.radius as f32,
+            egui::Color32::TRANSPARENT,
+            egui::Stroke::new(1.0, stroke),
+        );
+    }
+}
+
+pub fn draw_apollonius_circle(
+    ui: &mut egui::Ui,
+    circle: &Circle,
+    condition: bool,
+    stroke: egui::Color32,
+) {
+    if !condition {
+        return;
+    }
+
+    draw_circle(
+        ui,
+        to_pos2(circle.center),
+        circle.radius as f32,
+        egui::Color32::TRANSPARENT,
+        egui::Stroke::new(0.5, stroke),
+    );
+}
+
+pub fn draw_locus(ui: &mut egui::Ui, locus: &Locus, stroke: egui::Color32) {
+    if locus.points.len() < 2 {
+        return;
+    }
+
+    ui.painter().add(egui::Shape::line(
+        locus.points.iter().map(|point| to_pos2(*point)).collect(),
+        egui::Stroke::new(1.0, stroke),
+    ));
+}
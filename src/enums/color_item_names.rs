@@ -1,10 +1,26 @@
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ColorItemNames {
     InitialCircles,
+    UnselectedInputs,
     HomotheticCenters,
     Radical,
     InversePoles1,
     InversePoles2,
     InversePoles3,
     InversePoles4,
+    Algebraic,
+    Flagged,
+    Selected,
+}
+
+impl ColorItemNames {
+    // A solution shares the color of its Gergonne pair, i.e. of itself and its flipped signature
+    pub fn from_pair_index(pair_index: usize) -> Self {
+        match pair_index {
+            0 => ColorItemNames::InversePoles1,
+            1 => ColorItemNames::InversePoles2,
+            2 => ColorItemNames::InversePoles3,
+            _ => ColorItemNames::InversePoles4,
+        }
+    }
 }
@@ -0,0 +1,92 @@
+// Steps of the Gergonne construction, revealed one after the other in construction mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ConstructionStep {
+    Inputs,
+    HomotheticCenters,
+    HomotheticAxes,
+    RadicalCenter,
+    InversePoles,
+    PoleLines,
+    TouchPoints,
+    Solutions,
+}
+
+impl ConstructionStep {
+    pub fn value(&self) -> String {
+        match *self {
+            ConstructionStep::Inputs => String::from("Inputs"),
+            ConstructionStep::HomotheticCenters => String::from("Homothetic centers"),
+            ConstructionStep::HomotheticAxes => String::from("Homothetic axes"),
+            ConstructionStep::RadicalCenter => String::from("Radical center"),
+            ConstructionStep::InversePoles => String::from("Inverse poles"),
+            ConstructionStep::PoleLines => String::from("Pole lines"),
+            ConstructionStep::TouchPoints => String::from("Touch points"),
+            ConstructionStep::Solutions => String::from("Solutions"),
+        }
+    }
+
+    pub fn caption(&self) -> String {
+        match *self {
+            ConstructionStep::Inputs => String::from("Start from the three given circles."),
+            ConstructionStep::HomotheticCenters => String::from(
+                "Every pair of circles has an external and an internal center of similitude, \
+                 where their common tangents cross.",
+            ),
+            ConstructionStep::HomotheticAxes => String::from(
+                "The six centers lie three by three on four lines, the homothetic axes. Each \
+                 axis leads to one pair of solutions.",
+            ),
+            ConstructionStep::RadicalCenter => String::from(
+                "The radical axes of the three pairs meet at the radical center, the point with \
+                 the same power with respect to every circle.",
+            ),
+            ConstructionStep::InversePoles => String::from(
+                "Each homothetic axis has a pole with respect to every circle, the point whose \
+                 polar is that axis.",
+            ),
+            ConstructionStep::PoleLines => String::from("Join the radical center to every pole."),
+            ConstructionStep::TouchPoints => String::from(
+                "Each of these lines cuts its circle at the two points where a pair of solutions \
+                 touches it.",
+            ),
+            ConstructionStep::Solutions => String::from(
+                "The touch points on one side, and those on the other, give the two solutions \
+                 of each axis, eight circles in all.",
+            ),
+        }
+    }
+
+    pub fn as_vec() -> Vec<Self> {
+        vec![
+            Self::Inputs,
+            Self::HomotheticCenters,
+            Self::HomotheticAxes,
+            Self::RadicalCenter,
+            Self::InversePoles,
+            Self::PoleLines,
+            Self::TouchPoints,
+            Self::Solutions,
+        ]
+    }
+
+    pub fn index(&self) -> usize {
+        Self::as_vec()
+            .iter()
+            .position(|step| step == self)
+            .unwrap_or_default()
+    }
+
+    pub fn next(&self) -> Self {
+        Self::as_vec()
+            .get(self.index() + 1)
+            .copied()
+            .unwrap_or(*self)
+    }
+
+    pub fn previous(&self) -> Self {
+        match self.index() {
+            0 => *self,
+            idx => Self::as_vec()[idx - 1],
+        }
+    }
+}
@@ -1,7 +1,19 @@
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Dragging {
-    Circle1,
-    Circle2,
-    Circle3,
+    // Moves the whole input at this index
+    Input(usize),
+    // Moves one end of the line input at this index
+    Handle(usize, usize),
+    // Resizes the circle input at this index from its boundary
+    Rim(usize),
     None,
 }
+impl Dragging {
+    // Input being dragged, whatever part of it was grabbed
+    pub fn input_index(&self) -> Option<usize> {
+        match *self {
+            Dragging::Input(idx) | Dragging::Handle(idx, _) | Dragging::Rim(idx) => Some(idx),
+            Dragging::None => None,
+        }
+    }
+}
@@ -1,3 +1,4 @@
 pub mod color_item_names;
+pub mod construction_step;
 pub mod dragging;
 pub mod theme_mode;
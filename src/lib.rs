@@ -0,0 +1,17 @@
+pub mod models;
+pub mod services;
+
+pub use models::{
+    circle::Circle,
+    configuration::{Configuration, ConfigurationClass},
+    geometry_error::GeometryError,
+    input::{Input, InputKind},
+    locus::Locus,
+    solution::Solution,
+    solution_tracker::{SolutionTracker, TrackedSolution},
+    solutions::Solutions,
+    solver_method::SolverMethod,
+    tangency::{Tangency, TangencySignature},
+    tolerance::Tolerance,
+};
+pub use services::solver::{solve, solve_apollonius};
@@ -1,11 +1,14 @@
+use std::path::PathBuf;
+
+use app::{MyApp, scene_file::SceneFile};
 use eframe::egui;
-use models::app::MyApp;
-use panels::{bottom_panel, central_panel, top_panel};
+use panels::{bottom_panel, central_panel, inputs_panel, settings_panel, top_panel, traces_panel};
 
+mod app;
+mod draw;
 mod enums;
-mod models;
 mod panels;
-mod services;
+mod theme;
 
 fn main() -> eframe::Result {
     let options = eframe::NativeOptions {
@@ -19,19 +22,43 @@ fn main() -> eframe::Result {
             // This gives us image support:
             egui_extras::install_image_loaders(&cc.egui_ctx);
 
-            Ok(Box::<MyApp>::default())
+            // A scene file given as the first argument wins over the last session
+            let mut app = MyApp::from_storage(cc.storage);
+            if let Some(path) = std::env::args().nth(1) {
+                app.load_scene(&PathBuf::from(&path));
+                app.scene_path = path;
+            }
+
+            Ok(Box::new(app))
         }),
     )
 }
 
 impl eframe::App for MyApp {
+    // Only the scene is kept, eframe restores the window size itself. Open panels, tolerance and
+    // keyboard steps start from their defaults every session.
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, eframe::APP_KEY, &SceneFile::from_app(self));
+    }
+
+    fn raw_input_hook(&mut self, ctx: &egui::Context, raw_input: &mut egui::RawInput) {
+        self.handle_keyboard_editing(ctx, raw_input);
+    }
+
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         let mut style: egui::Style = (*ctx.style()).clone();
         style.visuals = self.theme_mode.get_theme_visuals();
         ctx.set_style(style);
 
+        self.handle_history_shortcuts(ctx);
         top_panel::get(self, ctx);
         bottom_panel::get(self, ctx);
+        settings_panel::get(self, ctx);
+        traces_panel::get(self, ctx);
+        inputs_panel::get(self, ctx);
         central_panel::get(self, ctx);
+
+        let is_interacting = ctx.input(|i| i.pointer.any_down());
+        self.history.record(self.snapshot(), is_interacting);
     }
 }
@@ -1,48 +1,56 @@
 use crate::models::circle::Circle;
+use crate::models::geometry_error::GeometryError;
 use crate::models::inverse_pole_set::InversePoleSet;
+use crate::models::point::Point;
 use crate::models::segment::Segment;
+use crate::models::tolerance::Tolerance;
 
 #[derive(Clone, Copy)]
 pub struct ApolloniusPair {
-    pub circle_1: Option<Circle>,
-    pub circle_2: Option<Circle>,
+    pub circle_1: Result<Circle, GeometryError>,
+    pub circle_2: Result<Circle, GeometryError>,
 }
 impl ApolloniusPair {
     pub fn get_apollonius_circles(
-        inverse_pole_set: &Option<InversePoleSet>,
+        inverse_pole_set: &Result<InversePoleSet, GeometryError>,
         ord: (i8, i8, i8),
+        tolerance: &Tolerance,
     ) -> ApolloniusPair {
-        let mut circle_1: Option<Circle> = None;
-        let mut circle_2: Option<Circle> = None;
-
-        if let Some(set) = inverse_pole_set {
-            fn get_segment_point(num: i8, s: &Segment) -> egui::Pos2 {
-                if num == 0 { s.0 } else { s.1 }
+        let set = match inverse_pole_set {
+            Ok(set) => set,
+            Err(error) => {
+                return ApolloniusPair {
+                    circle_1: Err(*error),
+                    circle_2: Err(*error),
+                };
             }
-            let get_option_point = |idx: usize, ord: i8| -> Option<egui::Pos2> {
-                set.get_segment(idx)
-                    .as_ref()
-                    .map(|s| get_segment_point(ord, s))
-            };
+        };
+
+        fn get_segment_point(num: i8, s: &Segment) -> Point {
+            if num == 0 { s.0 } else { s.1 }
+        }
+        let get_circle = |ord: (i8, i8, i8)| -> Result<Circle, GeometryError> {
+            Circle::get_circle_3_points(
+                get_segment_point(ord.0, &set.get_segment(0)?),
+                get_segment_point(ord.1, &set.get_segment(1)?),
+                get_segment_point(ord.2, &set.get_segment(2)?),
+                tolerance,
+            )
+        };
 
-            circle_1 = Circle::get_circle_3_points(
-                &get_option_point(0, ord.0),
-                &get_option_point(1, ord.1),
-                &get_option_point(2, ord.2),
-            );
-            circle_2 = Circle::get_circle_3_points(
-                &get_option_point(0, 1 - ord.0),
-                &get_option_point(1, 1 - ord.1),
-                &get_option_point(2, 1 - ord.2),
-            );
+        ApolloniusPair {
+            circle_1: get_circle(ord),
+            circle_2: get_circle((1 - ord.0, 1 - ord.1, 1 - ord.2)),
         }
+    }
 
-        ApolloniusPair { circle_1, circle_2 }
+    pub fn get_error(&self) -> Option<GeometryError> {
+        self.into_iter().find_map(Result::err)
     }
 }
 impl IntoIterator for ApolloniusPair {
-    type Item = Option<Circle>;
-    type IntoIter = std::array::IntoIter<Option<Circle>, 2>;
+    type Item = Result<Circle, GeometryError>;
+    type IntoIter = std::array::IntoIter<Result<Circle, GeometryError>, 2>;
 
     fn into_iter(self) -> Self::IntoIter {
         IntoIterator::into_iter([self.circle_1, self.circle_2])
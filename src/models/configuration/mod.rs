@@ -0,0 +1,186 @@
+use crate::models::{circle::Circle, input::Input, tolerance::Tolerance, vector::Vector};
+
+// How two circles lie relative to each other, points being circles of radius zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Relation {
+    Separate,
+    Crossing,
+    // The first circle has the second one inside
+    Contains,
+    Inside,
+    Tangent,
+    Coincident,
+}
+impl Relation {
+    fn between(c1: &Circle, c2: &Circle, tolerance: &Tolerance) -> Self {
+        let distance = c1.center.distance(c2.center);
+        let outer = c1.radius + c2.radius;
+        let inner = (c1.radius - c2.radius).abs();
+
+        if tolerance.is_same_point(c1.center, c2.center) && tolerance.is_equal(c1.radius, c2.radius)
+        {
+            Relation::Coincident
+        } else if tolerance.is_equal(distance, outer) || tolerance.is_equal(distance, inner) {
+            Relation::Tangent
+        } else if distance > outer {
+            Relation::Separate
+        } else if distance > inner {
+            Relation::Crossing
+        } else if c1.radius > c2.radius {
+            Relation::Contains
+        } else {
+            Relation::Inside
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigurationClass {
+    // No circle touches or contains another
+    Disjoint,
+    // One circle holds the two others, which are apart
+    Enclosed,
+    // Some circle parts the others, which rules out any solution: one input lies inside another
+    // and the third outside it, or the three are nested one in the next
+    Separated,
+    // Number of pairs crossing at two points
+    Intersecting(usize),
+    // Some pair touches, the count then depends on more than the pairwise relations
+    Tangent,
+    // Two inputs are the same circle or point
+    Coincident,
+    WithLines,
+}
+impl ConfigurationClass {
+    pub fn value(&self) -> String {
+        match *self {
+            ConfigurationClass::Disjoint => String::from("Disjoint"),
+            ConfigurationClass::Enclosed => String::from("Enclosed"),
+            ConfigurationClass::Separated => String::from("Separated"),
+            ConfigurationClass::Intersecting(crossings) => format!("Intersecting ({crossings})"),
+            ConfigurationClass::Tangent => String::from("Tangent"),
+            ConfigurationClass::Coincident => String::from("Coincident"),
+            ConfigurationClass::WithLines => String::from("With lines"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Configuration {
+    pub class: ConfigurationClass,
+    pub point_count: usize,
+    // Some line touches all three circles and stands in for one of the solutions
+    pub has_common_tangent: bool,
+}
+impl Configuration {
+    pub fn classify(inputs: &[Input; 3], tolerance: &Tolerance) -> Self {
+        let point_count = inputs
+            .iter()
+            .filter(|input| input.as_circle().is_some_and(|c| c.is_point(tolerance)))
+            .count();
+        let [Some(c0), Some(c1), Some(c2)] = inputs.map(|input| input.as_circle()) else {
+            return Self {
+                class: ConfigurationClass::WithLines,
+                point_count,
+                has_common_tangent: false,
+            };
+        };
+
+        let circles = [c0, c1, c2];
+        let relation = |i: usize, j: usize| Relation::between(&circles[i], &circles[j], tolerance);
+        let relations = [relation(0, 1), relation(1, 2), relation(2, 0)];
+
+        let class = if relations.contains(&Relation::Coincident) {
+            ConfigurationClass::Coincident
+        } else if relations.contains(&Relation::Tangent) {
+            ConfigurationClass::Tangent
+        } else if relations.contains(&Relation::Crossing) {
+            let crossings = relations
+                .iter()
+                .filter(|relation| **relation == Relation::Crossing)
+                .count();
+            ConfigurationClass::Intersecting(crossings)
+        } else {
+            // Times each circle holds one of the others
+            let held = (0..3).map(|i| {
+                (0..3)
+                    .filter(|&j| j != i && relation(i, j) == Relation::Contains)
+                    .count()
+            });
+            let mut counts: Vec<usize> = held.collect();
+            counts.sort_unstable();
+            match counts[..] {
+                [0, 0, 0] => ConfigurationClass::Disjoint,
+                [0, 0, 2] => ConfigurationClass::Enclosed,
+                _ => ConfigurationClass::Separated,
+            }
+        };
+
+        Self {
+            class,
+            point_count,
+            has_common_tangent: has_common_tangent(&circles, tolerance),
+        }
+    }
+
+    // Each point input merges the solutions touching it from either side, halving the count.
+    // None when the count is infinite or not fixed by the class. A common tangent line takes
+    // the place of a circle, and near one the far away solutions may or may not be found.
+    pub fn expected_count(&self) -> Option<usize> {
+        if self.has_common_tangent {
+            return None;
+        }
+
+        let circle_count = match self.class {
+            ConfigurationClass::Disjoint | ConfigurationClass::Enclosed => 8,
+            ConfigurationClass::Separated => 0,
+            ConfigurationClass::Intersecting(3) => 8,
+            ConfigurationClass::Intersecting(_) => 4,
+            ConfigurationClass::Tangent
+            | ConfigurationClass::Coincident
+            | ConfigurationClass::WithLines => return None,
+        };
+
+        Some(circle_count >> self.point_count)
+    }
+}
+
+// A line n . p = d with |n| = 1 touches circle i when n . ci - d = si * ri. Taking the first
+// equation off the others leaves n . (ci - c0) = si * ri - s0 * r0, linear in n.
+fn has_common_tangent(circles: &[Circle; 3], tolerance: &Tolerance) -> bool {
+    let [c0, c1, c2] = circles;
+    let (u1, u2) = (c1.center - c0.center, c2.center - c0.center);
+    let scale = u1.length().max(u2.length());
+
+    // Flipping every sign gives the same line, so the first circle can stay external
+    [(1.0, 1.0), (1.0, -1.0), (-1.0, 1.0), (-1.0, -1.0)]
+        .iter()
+        .any(|(s1, s2)| {
+            let k1 = s1 * c1.radius - c0.radius;
+            let k2 = s2 * c2.radius - c0.radius;
+            let determinant = u1.cross(u2);
+            if !tolerance.is_zero(determinant, u1.length() * u2.length()) {
+                let n =
+                    Vector::new(k1 * u2.y - k2 * u1.y, k2 * u1.x - k1 * u2.x) * (1.0 / determinant);
+                return tolerance.is_zero((n.length() - 1.0) * scale, scale);
+            }
+
+            // Collinear centers, both equations fix the component of n along the center line
+            let (u, k) = if u1.length() >= u2.length() {
+                (u1, k1)
+            } else {
+                (u2, k2)
+            };
+            if tolerance.is_zero(u.length(), scale) {
+                return false;
+            }
+            let (v, l) = if u1.length() >= u2.length() {
+                (u2, k2)
+            } else {
+                (u1, k1)
+            };
+            let along = k / u.length();
+            let ratio = v.dot(u) / u.dot(u);
+            tolerance.is_zero(l - ratio * k, scale) && along.abs() <= 1.0
+        })
+}
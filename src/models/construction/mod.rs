@@ -0,0 +1,40 @@
+use crate::models::{
+    apollonius_pair::ApolloniusPair, circle::Circle, geometry_error::GeometryError,
+    homothetic_set::HomotheticSet, inverse_pole_set::InversePoleSet, point::Point,
+    segment::Segment,
+};
+
+// Intermediate steps of the Gergonne construction, only available when all inputs are circles.
+// The per pair arrays follow TangencySignature::pair_index, so they keep to the input order
+// whatever the radii are.
+pub struct Construction {
+    pub homothetic_set: HomotheticSet,
+    pub homothetic_axes: [Option<Segment>; 4],
+    pub radical_center: Result<Point, GeometryError>,
+    pub inverse_pole_sets: [Result<InversePoleSet, GeometryError>; 4],
+    pub apollonius_pairs: [ApolloniusPair; 4],
+}
+impl Construction {
+    pub fn circles(&self) -> impl Iterator<Item = Circle> {
+        self.apollonius_pairs
+            .into_iter()
+            .flat_map(|pair| pair.into_iter().flatten())
+    }
+
+    // The construction degenerates when a pair is missing a circle or produced non-finite values
+    pub fn is_degenerate(&self) -> bool {
+        self.apollonius_pairs.iter().any(|pair| {
+            pair.into_iter().any(|circle| match circle {
+                Ok(c) => {
+                    !(c.center.x.is_finite() && c.center.y.is_finite() && c.radius.is_finite())
+                }
+                Err(_) => true,
+            })
+        })
+    }
+
+    // Why each pair, in construction order, could not be built
+    pub fn get_errors(&self) -> [Option<GeometryError>; 4] {
+        self.apollonius_pairs.map(|pair| pair.get_error())
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryError {
+    ParallelLines,
+    ConcurrentLines,
+    CoincidentCircles,
+    CoincidentPoints,
+    CollinearPoints,
+    DegenerateLine,
+    NoIntersection,
+    MissingHomotheticAxis,
+    PointInput,
+}
+impl fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match *self {
+            GeometryError::ParallelLines => "the lines are parallel",
+            GeometryError::ConcurrentLines => "the lines meet in a single point",
+            GeometryError::CoincidentCircles => "the circles coincide",
+            GeometryError::CoincidentPoints => "the points coincide",
+            GeometryError::CollinearPoints => "the points are collinear",
+            GeometryError::DegenerateLine => "the line has no direction",
+            GeometryError::NoIntersection => "the shapes do not intersect",
+            GeometryError::MissingHomotheticAxis => "the homothetic axis does not exist",
+            GeometryError::PointInput => "points have no poles or polars",
+        };
+        write!(f, "{message}")
+    }
+}
+impl std::error::Error for GeometryError {}
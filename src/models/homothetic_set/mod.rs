@@ -1,17 +1,17 @@
-use egui::Pos2;
+use crate::models::point::Point;
 
-use crate::models::{circle::Circle, segment::Segment};
+use crate::models::{circle::Circle, segment::Segment, tolerance::Tolerance};
 
 #[derive(Clone)]
 pub struct HomotheticPair {
-    pub ex: Option<Pos2>,
-    pub ir: Option<Pos2>,
+    pub ex: Option<Point>,
+    pub ir: Option<Point>,
 }
 impl HomotheticPair {
-    pub fn new(circle_1: Circle, circle_2: Circle) -> Self {
+    pub fn new(circle_1: Circle, circle_2: Circle, tolerance: &Tolerance) -> Self {
         Self {
-            ex: HomotheticSet::get_external_homothetic_center(circle_1, circle_2),
-            ir: HomotheticSet::get_internal_homothetic_center(circle_1, circle_2),
+            ex: HomotheticSet::get_external_homothetic_center(circle_1, circle_2, tolerance),
+            ir: HomotheticSet::get_internal_homothetic_center(circle_1, circle_2, tolerance),
         }
     }
 }
@@ -22,41 +22,54 @@ pub struct HomotheticSet {
     pub lines: [Option<Segment>; 4],
 }
 impl HomotheticSet {
-    pub fn new(circles: &[Circle]) -> Self {
+    pub fn new(circles: &[Circle], tolerance: &Tolerance) -> Self {
         let pairs: [HomotheticPair; 3] = [
-            HomotheticPair::new(circles[0], circles[1]),
-            HomotheticPair::new(circles[1], circles[2]),
-            HomotheticPair::new(circles[2], circles[0]),
+            HomotheticPair::new(circles[0], circles[1], tolerance),
+            HomotheticPair::new(circles[1], circles[2], tolerance),
+            HomotheticPair::new(circles[2], circles[0], tolerance),
         ];
 
         let lines: [Option<Segment>; 4] = [
-            Segment::get_any_valid_segment(vec![pairs[2].ex, pairs[1].ex, pairs[0].ex]),
-            Segment::get_any_valid_segment(vec![pairs[0].ex, pairs[2].ir, pairs[1].ir]),
-            Segment::get_any_valid_segment(vec![pairs[2].ex, pairs[1].ir, pairs[0].ir]),
-            Segment::get_any_valid_segment(vec![pairs[1].ex, pairs[2].ir, pairs[0].ir]),
+            Segment::get_any_valid_segment(vec![pairs[2].ex, pairs[1].ex, pairs[0].ex], tolerance),
+            Segment::get_any_valid_segment(vec![pairs[0].ex, pairs[2].ir, pairs[1].ir], tolerance),
+            Segment::get_any_valid_segment(vec![pairs[2].ex, pairs[1].ir, pairs[0].ir], tolerance),
+            Segment::get_any_valid_segment(vec![pairs[1].ex, pairs[2].ir, pairs[0].ir], tolerance),
         ];
 
         Self { pairs, lines }
     }
 
-    pub fn get_external_homothetic_center(c1: Circle, c2: Circle) -> Option<Pos2> {
+    pub fn get_external_homothetic_center(
+        c1: Circle,
+        c2: Circle,
+        tolerance: &Tolerance,
+    ) -> Option<Point> {
         let denominator = c2.radius - c1.radius;
-        if denominator.abs() < 10e-6 {
+        if tolerance.is_equal(c1.radius, c2.radius) {
             return None;
         }
 
         let x = (c2.radius * c1.center.x - c1.radius * c2.center.x) / denominator;
         let y = (c2.radius * c1.center.y - c1.radius * c2.center.y) / denominator;
 
-        Some(Pos2 { x, y })
+        Some(Point { x, y })
     }
 
-    // Unlike the beta external, internal is unfuckable
-    pub fn get_internal_homothetic_center(c1: Circle, c2: Circle) -> Option<Pos2> {
+    // The radii sum to the denominator, so with no negative radius it only vanishes when both
+    // circles are points, unlike the external center that fails for any two equal radii
+    pub fn get_internal_homothetic_center(
+        c1: Circle,
+        c2: Circle,
+        tolerance: &Tolerance,
+    ) -> Option<Point> {
         let denominator = c2.radius + c1.radius;
+        if c1.is_point(tolerance) && c2.is_point(tolerance) {
+            return None;
+        }
+
         let x = (c2.radius * c1.center.x + c1.radius * c2.center.x) / denominator;
         let y = (c2.radius * c1.center.y + c1.radius * c2.center.y) / denominator;
 
-        Some(Pos2 { x, y })
+        Some(Point { x, y })
     }
 }
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    circle::Circle, point::Point, segment::Segment, tangency::Tangency, tolerance::Tolerance,
+    vector::Vector,
+};
+use crate::services;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Point,
+    Line,
+    Circle,
+}
+impl InputKind {
+    pub fn value(&self) -> String {
+        match *self {
+            InputKind::Point => String::from("Point"),
+            InputKind::Line => String::from("Line"),
+            InputKind::Circle => String::from("Circle"),
+        }
+    }
+
+    pub fn as_vec() -> Vec<Self> {
+        vec![Self::Point, Self::Line, Self::Circle]
+    }
+}
+
+// One of the three given objects, a point being a circle of radius zero
+// and a line being the infinite line through both ends of the segment
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Input {
+    Circle(Circle),
+    Line(Segment),
+}
+impl Input {
+    pub fn kind(&self, tolerance: &Tolerance) -> InputKind {
+        match self {
+            Input::Circle(circle) if circle.is_point(tolerance) => InputKind::Point,
+            Input::Circle(_) => InputKind::Circle,
+            Input::Line(_) => InputKind::Line,
+        }
+    }
+
+    pub fn as_circle(&self) -> Option<Circle> {
+        match *self {
+            Input::Circle(circle) => Some(circle),
+            Input::Line(_) => None,
+        }
+    }
+
+    pub fn center(&self) -> Point {
+        match *self {
+            Input::Circle(circle) => circle.center,
+            Input::Line(segment) => services::calc::mid_point(&segment),
+        }
+    }
+
+    pub fn translate(&mut self, delta: Vector) {
+        match self {
+            Input::Circle(circle) => circle.center += delta,
+            Input::Line(segment) => {
+                segment.0 += delta;
+                segment.1 += delta;
+            }
+        }
+    }
+
+    // Keeps the input roughly where it was while changing what it is
+    pub fn with_kind(&self, kind: InputKind, tolerance: &Tolerance) -> Self {
+        const DEFAULT_RADIUS: f64 = 30.0;
+
+        let center = self.center();
+        let size = match *self {
+            Input::Circle(circle) if !circle.is_point(tolerance) => circle.radius,
+            Input::Line(segment) => segment.0.distance(segment.1) / 2.0,
+            Input::Circle(_) => DEFAULT_RADIUS,
+        };
+
+        match kind {
+            InputKind::Point => Input::Circle(Circle {
+                center,
+                radius: 0.0,
+            }),
+            InputKind::Circle => Input::Circle(Circle {
+                center,
+                radius: size,
+            }),
+            InputKind::Line => Input::Line(Segment(
+                center - Vector::new(size, 0.0),
+                center + Vector::new(size, 0.0),
+            )),
+        }
+    }
+
+    // Lines count as circles of infinite radius whose inside is their negative side, so a
+    // solution on the positive side of the segment is externally tangent to it
+    pub fn get_tangency(&self, solution: &Circle) -> Tangency {
+        match self {
+            Input::Circle(circle) => solution.get_tangency(circle),
+            Input::Line(segment) => {
+                if segment.signed_distance(solution.center) >= 0.0 {
+                    Tangency::External
+                } else {
+                    Tangency::Internal
+                }
+            }
+        }
+    }
+
+    // Distance from the solution touching the input the given way, zero for an exact tangency
+    pub fn tangency_residual(&self, solution: &Circle, kind: Tangency) -> f64 {
+        match self {
+            Input::Circle(circle) => solution.tangency_residual(circle, kind),
+            Input::Line(segment) => {
+                let distance = segment.signed_distance(solution.center);
+                match kind {
+                    Tangency::External => (distance - solution.radius).abs(),
+                    Tangency::Internal => (distance + solution.radius).abs(),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::models::{
+    circle::Circle, input::Input, point::Point, segment::Segment, tolerance::Tolerance,
+};
+
+// Inversion in the circle around `center` whose radius squared is `power`
+#[derive(Clone, Copy, Debug)]
+pub struct Inversion {
+    pub center: Point,
+    pub power: f64,
+}
+impl Inversion {
+    pub fn invert_point(&self, p: Point) -> Option<Point> {
+        let offset = p - self.center;
+        let squared = offset.dot(offset);
+        if squared == 0.0 {
+            return None;
+        }
+
+        Some(self.center + offset * (self.power / squared))
+    }
+
+    // Circles through the center turn into lines, every other circle stays a circle
+    pub fn invert_circle(&self, circle: &Circle, tolerance: &Tolerance) -> Option<Input> {
+        let offset = circle.center - self.center;
+        let distance = offset.length();
+
+        if tolerance.is_equal(distance, circle.radius) {
+            if circle.is_point(tolerance) {
+                return None;
+            }
+            // The far end of the diameter through the center lands on the line
+            let direction = offset * (1.0 / distance);
+            let foot = self.center + direction * (self.power / (2.0 * circle.radius));
+            let along = direction.rot90() * circle.radius;
+            return Some(Input::Line(Segment(foot - along, foot + along)));
+        }
+
+        let denominator = offset.dot(offset) - circle.radius.powi(2);
+        Some(Input::Circle(Circle {
+            center: self.center + offset * (self.power / denominator),
+            radius: self.power * circle.radius / denominator.abs(),
+        }))
+    }
+
+    // Lines away from the center become circles through it, lines through it stay put
+    pub fn invert_line(&self, segment: &Segment, tolerance: &Tolerance) -> Option<Circle> {
+        let distance = segment.signed_distance(self.center);
+        if tolerance.is_zero(distance, segment.0.distance(segment.1)) {
+            return None;
+        }
+
+        // The foot of the perpendicular from the center lands at the far end of a diameter
+        let direction = segment.1 - segment.0;
+        let normal = direction.rot90() * (1.0 / direction.length());
+        let foot = self.center - normal * distance;
+        let far = self.invert_point(foot)?;
+        Some(Circle {
+            center: self.center + (far - self.center) * 0.5,
+            radius: self.power / (2.0 * distance.abs()),
+        })
+    }
+}
@@ -0,0 +1,34 @@
+use crate::models::{point::Point, tolerance::Tolerance};
+
+// Path traced by a solution center while the inputs move
+#[derive(Clone, Debug, Default)]
+pub struct Locus {
+    pub points: Vec<Point>,
+}
+impl Locus {
+    // Frames where nothing moved would only pile up the same point
+    pub fn record(&mut self, point: Point, tolerance: &Tolerance) {
+        if self
+            .points
+            .last()
+            .is_none_or(|last| !tolerance.is_same_point(*last, point))
+        {
+            self.points.push(point);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.points.clear();
+    }
+
+    pub fn to_csv(&self) -> String {
+        std::iter::once(String::from("x,y"))
+            .chain(
+                self.points
+                    .iter()
+                    .map(|point| format!("{},{}", point.x, point.y)),
+            )
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
@@ -1,7 +1,20 @@
 pub mod apollonius_pair;
-pub mod app;
 pub mod circle;
+pub mod configuration;
+pub mod construction;
+pub mod geometry_error;
 pub mod homothetic_set;
+pub mod input;
 pub mod inverse_pole_set;
+pub mod inversion;
+pub mod locus;
+pub mod point;
 pub mod segment;
+pub mod solution;
+pub mod solution_tracker;
+pub mod solutions;
+pub mod solver_method;
 pub mod straightline;
+pub mod tangency;
+pub mod tolerance;
+pub mod vector;
@@ -1,10 +1,18 @@
-use egui::Pos2;
+use serde::{Deserialize, Serialize};
 
-use super::straightline::StraightLine;
+use crate::models::point::Point;
 
-#[derive(Clone, Copy)]
-pub struct Segment(pub Pos2, pub Pos2);
+use super::{straightline::StraightLine, tolerance::Tolerance};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Segment(pub Point, pub Point);
 impl Segment {
+    // Positive on the side 0 -> 1 turns counter-clockwise towards
+    pub fn signed_distance(&self, p: Point) -> f64 {
+        let direction = self.1 - self.0;
+        direction.cross(p - self.0) / direction.length()
+    }
+
     pub fn as_straight_line(&self) -> StraightLine {
         StraightLine {
             a: self.1.y - self.0.y,
@@ -13,12 +21,17 @@ impl Segment {
         }
     }
 
-    pub fn get_any_valid_segment(points: Vec<Option<Pos2>>) -> Option<Self> {
-        let filtered_points: Vec<Pos2> = points.into_iter().flatten().collect();
-        if filtered_points.len() < 2 {
-            return None;
-        }
+    // Coincident points would not give the segment a direction, so they are skipped
+    pub fn get_any_valid_segment(
+        points: Vec<Option<Point>>,
+        tolerance: &Tolerance,
+    ) -> Option<Self> {
+        let filtered_points: Vec<Point> = points.into_iter().flatten().collect();
+        let first = *filtered_points.first()?;
+        let second = filtered_points
+            .into_iter()
+            .find(|point| !tolerance.is_same_point(first, *point))?;
 
-        Some(Segment(filtered_points[0], filtered_points[1]))
+        Some(Segment(first, second))
     }
 }
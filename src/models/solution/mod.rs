@@ -0,0 +1,31 @@
+use crate::models::{
+    circle::Circle, input::Input, tangency::TangencySignature, tolerance::Tolerance,
+};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Solution {
+    pub circle: Circle,
+    pub signature: TangencySignature,
+    // Largest tangency residual over the three inputs
+    pub residual: f64,
+}
+impl Solution {
+    pub fn classify(circle: Circle, inputs: &[Input; 3]) -> Self {
+        let signature = TangencySignature(inputs.map(|input| input.get_tangency(&circle)));
+        let residual = inputs
+            .iter()
+            .zip(signature.0)
+            .map(|(input, kind)| input.tangency_residual(&circle, kind))
+            .fold(0.0, f64::max);
+
+        Self {
+            circle,
+            signature,
+            residual,
+        }
+    }
+
+    pub fn is_tangent(&self, tolerance: &Tolerance) -> bool {
+        tolerance.is_zero(self.residual, self.circle.radius)
+    }
+}
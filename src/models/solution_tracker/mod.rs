@@ -0,0 +1,68 @@
+use crate::models::solution::Solution;
+
+// A solution with the branch it was matched to across solves
+#[derive(Clone, Copy, Debug)]
+pub struct TrackedSolution {
+    pub id: usize,
+    // Gergonne pair the branch was first seen in, kept even when its signature changes later
+    pub pair_index: usize,
+    pub solution: Solution,
+}
+
+// Follows the solutions from one solve to the next while the inputs move
+#[derive(Clone, Debug, Default)]
+pub struct SolutionTracker {
+    pub tracked: Vec<TrackedSolution>,
+    next_id: usize,
+}
+impl SolutionTracker {
+    // Pairs each solution with the closest previous one, those of the same signature first.
+    // Solutions left over start new branches.
+    pub fn update(&mut self, solutions: &[Solution]) -> &[TrackedSolution] {
+        let mut candidates: Vec<(bool, f64, usize, usize)> = Vec::new();
+        for (new_idx, solution) in solutions.iter().enumerate() {
+            for (old_idx, previous) in self.tracked.iter().enumerate() {
+                let old = previous.solution;
+                let distance = old.circle.center.distance(solution.circle.center)
+                    + (old.circle.radius - solution.circle.radius).abs();
+                let signature_changed = old.signature != solution.signature;
+                candidates.push((signature_changed, distance, new_idx, old_idx));
+            }
+        }
+        candidates.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+        let mut matches: Vec<Option<usize>> = vec![None; solutions.len()];
+        let mut taken: Vec<bool> = vec![false; self.tracked.len()];
+        for (_, _, new_idx, old_idx) in candidates {
+            if matches[new_idx].is_none() && !taken[old_idx] {
+                matches[new_idx] = Some(old_idx);
+                taken[old_idx] = true;
+            }
+        }
+
+        self.tracked = solutions
+            .iter()
+            .zip(matches)
+            .map(|(solution, matched)| match matched {
+                Some(old_idx) => TrackedSolution {
+                    solution: *solution,
+                    ..self.tracked[old_idx]
+                },
+                None => {
+                    self.next_id += 1;
+                    TrackedSolution {
+                        id: self.next_id,
+                        pair_index: solution.signature.pair_index(),
+                        solution: *solution,
+                    }
+                }
+            })
+            .collect();
+
+        &self.tracked
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -0,0 +1,40 @@
+use crate::models::{
+    circle::Circle, configuration::Configuration, construction::Construction, solution::Solution,
+    solver_method::SolverMethod, tolerance::Tolerance,
+};
+
+pub struct Solutions {
+    pub configuration: Configuration,
+    pub construction: Option<Construction>,
+    pub algebraic_circles: Vec<Circle>,
+    pub tangent_circles: Vec<Solution>,
+    pub method: SolverMethod,
+}
+impl Solutions {
+    pub fn is_degenerate(&self) -> bool {
+        self.construction
+            .as_ref()
+            .is_none_or(Construction::is_degenerate)
+    }
+
+    // False when circles are missing or spurious ones slipped through
+    pub fn has_expected_count(&self) -> bool {
+        self.configuration
+            .expected_count()
+            .is_none_or(|count| count == self.tangent_circles.len())
+    }
+
+    pub fn max_residual(&self) -> f64 {
+        self.tangent_circles
+            .iter()
+            .map(|solution| solution.residual)
+            .fold(0.0, f64::max)
+    }
+
+    pub fn get_flagged(&self, tolerance: &Tolerance) -> Vec<&Solution> {
+        self.tangent_circles
+            .iter()
+            .filter(|solution| !solution.is_tangent(tolerance))
+            .collect()
+    }
+}
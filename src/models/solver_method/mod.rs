@@ -0,0 +1,16 @@
+// Which path produced the tangent circles of a solve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverMethod {
+    Construction,
+    Inversion,
+    Algebraic,
+}
+impl SolverMethod {
+    pub fn value(&self) -> String {
+        match *self {
+            SolverMethod::Construction => String::from("Construction"),
+            SolverMethod::Inversion => String::from("Inversion"),
+            SolverMethod::Algebraic => String::from("Algebraic"),
+        }
+    }
+}
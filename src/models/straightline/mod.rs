@@ -1,6 +1,6 @@
 #[derive(Clone)]
-pub struct StraightLine{
-    pub a: f32,
-    pub b: f32,
-    pub c: f32,
-}
\ No newline at end of file
+pub struct StraightLine {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
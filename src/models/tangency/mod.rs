@@ -0,0 +1,88 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tangency {
+    External,
+    Internal,
+}
+impl Tangency {
+    pub fn value(&self) -> String {
+        match *self {
+            Tangency::External => String::from("External"),
+            Tangency::Internal => String::from("Internal"),
+        }
+    }
+
+    pub fn initial(&self) -> char {
+        match *self {
+            Tangency::External => 'E',
+            Tangency::Internal => 'I',
+        }
+    }
+
+    pub fn flipped(&self) -> Self {
+        match *self {
+            Tangency::External => Tangency::Internal,
+            Tangency::Internal => Tangency::External,
+        }
+    }
+}
+
+// Tangency of a solution to each of the three input circles, in input order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TangencySignature(pub [Tangency; 3]);
+impl TangencySignature {
+    pub const COUNT: usize = 8;
+
+    pub fn all() -> [Self; Self::COUNT] {
+        std::array::from_fn(Self::from_index)
+    }
+
+    // Bit i is set when the solution is internally tangent to input i
+    pub fn from_index(idx: usize) -> Self {
+        Self(std::array::from_fn(|bit| {
+            if idx & (1 << bit) == 0 {
+                Tangency::External
+            } else {
+                Tangency::Internal
+            }
+        }))
+    }
+
+    pub fn index(&self) -> usize {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(|(_, tangency)| **tangency == Tangency::Internal)
+            .map(|(bit, _)| 1 << bit)
+            .sum()
+    }
+
+    pub fn label(&self) -> String {
+        self.0.iter().map(Tangency::initial).collect()
+    }
+
+    pub fn description(&self) -> String {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(idx, tangency)| format!("{} to input {}", tangency.value(), idx + 1))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    pub fn flipped(&self) -> Self {
+        Self(self.0.map(|tangency| tangency.flipped()))
+    }
+
+    // Gergonne pairs are made of a signature and its flip, this picks the one external to the first input
+    pub fn pair_representative(&self) -> Self {
+        match self.0[0] {
+            Tangency::External => *self,
+            Tangency::Internal => self.flipped(),
+        }
+    }
+
+    // Number of the Gergonne pair, in the order of the inputs: EEE, EIE, EEI and EII
+    pub fn pair_index(&self) -> usize {
+        self.pair_representative().index() / 2
+    }
+}
@@ -0,0 +1,36 @@
+use crate::models::point::Point;
+
+// How close two values have to be to count as equal: the absolute part is in scene units and
+// keeps values near zero in check, the relative part scales with the magnitudes compared
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tolerance {
+    pub absolute: f64,
+    pub relative: f64,
+}
+impl Tolerance {
+    pub const DEFAULT_ABSOLUTE: f64 = 1e-6;
+    pub const DEFAULT_RELATIVE: f64 = 1e-9;
+
+    pub fn new(absolute: f64, relative: f64) -> Self {
+        Self { absolute, relative }
+    }
+
+    // `scale` is the magnitude of what `value` was computed from
+    pub fn is_zero(&self, value: f64, scale: f64) -> bool {
+        value.abs() <= self.absolute + self.relative * scale.abs()
+    }
+
+    pub fn is_equal(&self, a: f64, b: f64) -> bool {
+        self.is_zero(a - b, a.abs().max(b.abs()))
+    }
+
+    pub fn is_same_point(&self, p1: Point, p2: Point) -> bool {
+        let scale = p1.to_vector().length().max(p2.to_vector().length());
+        self.is_zero(p1.distance(p2), scale)
+    }
+}
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_ABSOLUTE, Self::DEFAULT_RELATIVE)
+    }
+}
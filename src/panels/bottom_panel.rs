@@ -1,37 +1,111 @@
 use std::ops::RangeInclusive;
 
-use egui::{Context, Slider};
+use apollonius::models::{
+    circle::Circle,
+    input::{Input, InputKind},
+    point::Point,
+};
+use egui::{Context, Slider, SliderClamping};
 
-use crate::models::app::MyApp;
+use crate::app::{InitialCircles, MyApp};
 
 pub fn get(app: &mut MyApp, ctx: &Context) {
     egui::TopBottomPanel::bottom("bottom_control_panel")
         .frame(egui::Frame::side_top_panel(&ctx.style()).inner_margin(8.0))
         .show(ctx, |ui| {
-            const RADIUS_RANGE: RangeInclusive<f32> = 1.0..=200.0;
+            const RADIUS_RANGE: RangeInclusive<f64> = 0.0..=200.0;
             ui.horizontal_wrapped(|ui| {
+                let mut removed: Option<usize> = None;
+                for idx in 0..app.initial_circles.inputs.len() {
+                    let tolerance = app.tolerance;
+                    let can_remove = app.initial_circles.inputs.len() > InitialCircles::MIN_COUNT;
+                    let input = app.initial_circles.get_mut(idx);
+                    ui.vertical(|ui| {
+                        let mut kind = input.kind(&tolerance);
+                        ui.horizontal(|ui| {
+                            egui::ComboBox::from_label(format!("Input {}", idx + 1))
+                                .selected_text(kind.value())
+                                .show_ui(ui, |ui| {
+                                    for kind_item in InputKind::as_vec() {
+                                        ui.selectable_value(
+                                            &mut kind,
+                                            kind_item,
+                                            kind_item.value(),
+                                        );
+                                    }
+                                });
+                            if ui
+                                .add_enabled(can_remove, egui::Button::new("Delete"))
+                                .clicked()
+                            {
+                                removed = Some(idx);
+                            }
+                        });
+                        if kind != input.kind(&tolerance) {
+                            *input = input.with_kind(kind, &tolerance);
+                        }
+
+                        if let Input::Circle(circle) = input {
+                            // Radii typed in the inputs panel may lie outside the range
+                            ui.add(
+                                Slider::new(&mut circle.radius, RADIUS_RANGE)
+                                    .clamping(SliderClamping::Edits),
+                            );
+                        }
+                    });
+                }
+                if let Some(idx) = removed {
+                    app.remove_input(idx);
+                }
+                if ui.add(egui::Button::new("Add circle")).clicked() {
+                    app.initial_circles.add(Input::Circle(Circle {
+                        center: Point::ORIGIN,
+                        radius: 30.0,
+                    }));
+                }
+
+                ui.separator();
+
+                // Which three inputs go through the solver
                 ui.vertical(|ui| {
-                    ui.label("Circle 1");
-                    ui.add(Slider::new(
-                        &mut app.initial_circles.circle_1.radius,
-                        RADIUS_RANGE,
-                    ));
-                });
-                ui.vertical(|ui| {
-                    ui.label("Circle 2");
-                    ui.add(Slider::new(
-                        &mut app.initial_circles.circle_2.radius,
-                        RADIUS_RANGE,
-                    ));
-                });
-                ui.vertical(|ui| {
-                    ui.label("Circle 3");
-                    ui.add(Slider::new(
-                        &mut app.initial_circles.circle_3.radius,
-                        RADIUS_RANGE,
-                    ));
+                    for slot in 0..3 {
+                        let mut idx = app.initial_circles.selected[slot];
+                        egui::ComboBox::from_label(format!("Solver input {}", slot + 1))
+                            .selected_text(format!("Input {}", idx + 1))
+                            .show_ui(ui, |ui| {
+                                for candidate in 0..app.initial_circles.inputs.len() {
+                                    ui.selectable_value(
+                                        &mut idx,
+                                        candidate,
+                                        format!("Input {}", candidate + 1),
+                                    );
+                                }
+                            });
+                        if idx != app.initial_circles.selected[slot] {
+                            app.initial_circles.select(slot, idx);
+                        }
+                    }
+                    ui.add_enabled_ui(app.construction_step.is_none(), |ui| {
+                        ui.checkbox(&mut app.display_options.show_every_triple, "Every triple")
+                            .on_hover_text("Also draw the solutions of all other triples");
+                    });
                 });
+                ui.separator();
 
+                if ui
+                    .add_enabled(app.history.can_undo(), egui::Button::new("Undo"))
+                    .on_hover_text("Ctrl+Z")
+                    .clicked()
+                {
+                    app.undo();
+                }
+                if ui
+                    .add_enabled(app.history.can_redo(), egui::Button::new("Redo"))
+                    .on_hover_text("Ctrl+Shift+Z")
+                    .clicked()
+                {
+                    app.redo();
+                }
                 ui.separator();
 
                 if ui.add(egui::Button::new("Reset camera")).clicked() {
@@ -1,212 +1,397 @@
+use apollonius::{
+    Circle, Solution, Solutions, Tolerance, TrackedSolution,
+    models::{input::Input, point::Point, vector::Vector},
+};
 use egui;
 
 use crate::{
     MyApp,
+    app::InitialCircles,
+    draw,
     enums::{color_item_names::ColorItemNames, dragging::Dragging},
-    models::{
-        apollonius_pair::ApolloniusPair, app::InitialCircles, homothetic_set::HomotheticSet,
-        inverse_pole_set::InversePoleSet, straightline::StraightLine,
-    },
-    services,
+    theme,
 };
 
+// Colors of the four Gergonne pairs, in construction order
+const PAIR_COLORS: [ColorItemNames; 4] = [
+    ColorItemNames::InversePoles1,
+    ColorItemNames::InversePoles2,
+    ColorItemNames::InversePoles3,
+    ColorItemNames::InversePoles4,
+];
+
 pub fn get(app: &mut MyApp, ctx: &egui::Context) {
     egui::CentralPanel::default().show(ctx, |ui| {
+        let solutions: Solutions =
+            apollonius::solve(&app.initial_circles.selected_inputs(), &app.tolerance);
+        let display_options = app.display_options.for_step(app.construction_step);
+        let tracked: Vec<TrackedSolution> = app
+            .solution_tracker
+            .update(&solutions.tangent_circles)
+            .to_vec();
+        for tracked_solution in &tracked {
+            if let Some(trace) = app.traces.get_mut(&tracked_solution.id) {
+                trace
+                    .locus
+                    .record(tracked_solution.solution.circle.center, &app.tolerance);
+            }
+        }
+
+        let other_solutions: Vec<Solution> = if display_options.show_every_triple {
+            solve_other_triples(&app.initial_circles, &app.tolerance)
+        } else {
+            Vec::new()
+        };
+
         let scene = egui::Scene::new().zoom_range(0.1..=50.0);
 
         scene.show(ui, &mut app.scene_rect, |ui: &mut egui::Ui| {
-            // Clipping rect bounding all 3 circles for handing indiviual circle dragging
+            // Screen pixels per scene unit, so the rim stays as easy to grab at any zoom
+            let zoom = ui
+                .ctx()
+                .layer_transform_to_global(ui.layer_id())
+                .map_or(1.0, |transform| transform.scaling);
+
+            // Clipping rect bounding all inputs for handing indiviual input dragging
             let union_3_circles_clipping_rect = app
                 .initial_circles
-                .circle_1
-                .get_circle_clipping_rect()
-                .union(app.initial_circles.circle_2.get_circle_clipping_rect())
-                .union(app.initial_circles.circle_3.get_circle_clipping_rect());
+                .inputs
+                .iter()
+                .map(draw::get_input_clipping_rect)
+                .reduce(|a, b| a.union(b))
+                .map_or(egui::Rect::NOTHING, |rect| {
+                    rect.expand(draw::POINT_RADIUS as f32 / zoom)
+                });
 
             // Handle mouse dragging events
             let response_circles =
                 ui.allocate_rect(union_3_circles_clipping_rect, egui::Sense::click_and_drag());
+            let hover_target = response_circles.hover_pos().and_then(|pos| {
+                find_drag_target(draw::to_point(pos), &app.initial_circles.inputs, zoom)
+            });
             handle_circles_drag_events(
-                response_circles,
+                &response_circles,
                 &mut app.initial_circles,
                 &mut app.is_dragging,
+                zoom,
+                ui.input(|i| i.modifiers),
             );
-
-            // Homothetic centers
-            let mut sorted_circles = app.initial_circles.as_array();
-            sorted_circles.sort_by(|a, b| a.radius.partial_cmp(&b.radius).unwrap());
-            let homothetic_set: HomotheticSet = HomotheticSet::new(&sorted_circles);
-
-            // Radical center
-            let radical_axes: [StraightLine; 2] = [
-                services::calc::get_radical_axis(
-                    app.initial_circles.circle_1,
-                    app.initial_circles.circle_2,
-                ),
-                services::calc::get_radical_axis(
-                    app.initial_circles.circle_2,
-                    app.initial_circles.circle_3,
-                ),
-            ];
-            let radical_center: egui::Pos2 =
-                services::calc::find_intersection(&radical_axes[0], &radical_axes[1]);
+            // Clicking or dragging an input selects it for the keyboard, clicking elsewhere
+            // clears the selection
+            if response_circles.clicked() || response_circles.drag_started() {
+                app.selected_input = app
+                    .is_dragging
+                    .input_index()
+                    .or(hover_target.and_then(|target| target.input_index()));
+            }
+            // Resize cursor over a rim and while resizing
+            let rim = match (app.is_dragging, hover_target) {
+                (Dragging::Rim(idx), _) | (Dragging::None, Some(Dragging::Rim(idx))) => Some(idx),
+                _ => None,
+            };
+            if let (Some(idx), Some(pos)) = (rim, response_circles.hover_pos()) {
+                let center = app.initial_circles.inputs[idx].center();
+                ui.ctx()
+                    .set_cursor_icon(resize_cursor(draw::to_point(pos) - center));
+            }
 
             // TODO: think of a way to nicely refactor the drawing steps
 
-            // let inv_pole_sets: Vec<Option<InversePoleSet>> = {
-            //     let mut sets: Vec<Option<InversePoleSet>> = vec![];
-            //     let mut first = true;
-            //     let same_radius = app.initial_circles.same_radius();
-            //     println!("are they same radius {}", same_radius);
-
-            //     for line in homothetic_set.lines {
-            //         if first && same_radius {
-            //             sets.push(InversePoleSet::new_special(&sorted_circles, radical_center));
-            //             first = false;
-            //             continue;
-            //         }
-
-            //         sets.push(InversePoleSet::new(line, &sorted_circles, radical_center));
-            //     }
-            //     sets
-            // };
-
-            // Inverse poles sets
-            let inv_pole_set_1 = if !app.initial_circles.same_radius() {
-                InversePoleSet::new(homothetic_set.lines[0], &sorted_circles, radical_center)
-            } else {
-                InversePoleSet::new_special(&sorted_circles, radical_center)
-            };
-            let inv_pole_set_2 =
-                InversePoleSet::new(homothetic_set.lines[1], &sorted_circles, radical_center);
-            let inv_pole_set_3 =
-                InversePoleSet::new(homothetic_set.lines[2], &sorted_circles, radical_center);
-            let inv_pole_set_4 =
-                InversePoleSet::new(homothetic_set.lines[3], &sorted_circles, radical_center);
-
-            // Apollonius pairs
-            let apollonius_pair_1 =
-                ApolloniusPair::get_apollonius_circles(&inv_pole_set_1, (0, 0, 0));
-            let apollonius_pair_2 =
-                ApolloniusPair::get_apollonius_circles(&inv_pole_set_2, (0, 0, 1));
-            let apollonius_pair_3 =
-                ApolloniusPair::get_apollonius_circles(&inv_pole_set_3, (1, 0, 1));
-            let apollonius_pair_4 =
-                ApolloniusPair::get_apollonius_circles(&inv_pole_set_4, (0, 1, 1));
-
             // Draw the shapes
-            services::draw::draw_three_circles(
-                ui,
-                app.initial_circles.as_array(),
-                services::theme::get_color(ColorItemNames::InitialCircles, &app.theme_mode),
-            );
-            services::draw::draw_homothetic_centers(
-                ui,
-                &homothetic_set,
-                app.display_options.show_homothetic,
-                services::theme::get_color(ColorItemNames::HomotheticCenters, &app.theme_mode),
-            );
-            services::draw::draw_radical_center(
-                ui,
-                radical_center,
-                app.display_options.show_radical,
-                services::theme::get_color(ColorItemNames::Radical, &app.theme_mode),
-            );
-            services::draw::draw_inverse_poles(
-                ui,
-                &inv_pole_set_1,
-                app.display_options.show_inverse_poles,
-                services::theme::get_color(ColorItemNames::InversePoles1, &app.theme_mode),
-            );
-            services::draw::draw_inverse_poles(
-                ui,
-                &inv_pole_set_2,
-                app.display_options.show_inverse_poles,
-                services::theme::get_color(ColorItemNames::InversePoles2, &app.theme_mode),
-            );
-            services::draw::draw_inverse_poles(
-                ui,
-                &inv_pole_set_3,
-                app.display_options.show_inverse_poles,
-                services::theme::get_color(ColorItemNames::InversePoles3, &app.theme_mode),
-            );
-            services::draw::draw_inverse_poles(
-                ui,
-                &inv_pole_set_4,
-                app.display_options.show_inverse_poles,
-                services::theme::get_color(ColorItemNames::InversePoles4, &app.theme_mode),
-            );
-            services::draw::draw_apollonius_circles_pair(
-                ui,
-                &apollonius_pair_1,
-                app.display_options.show_apollonius_circle_1,
-                services::theme::get_color(ColorItemNames::InversePoles1, &app.theme_mode),
-            );
-            services::draw::draw_apollonius_circles_pair(
+            let (selected, unselected): (Vec<_>, Vec<_>) = app
+                .initial_circles
+                .inputs
+                .iter()
+                .enumerate()
+                .partition(|(idx, _)| app.initial_circles.is_selected(*idx));
+            draw::draw_inputs(
                 ui,
-                &apollonius_pair_2,
-                app.display_options.show_apollonius_circle_2,
-                services::theme::get_color(ColorItemNames::InversePoles2, &app.theme_mode),
+                &unselected
+                    .into_iter()
+                    .map(|(_, input)| *input)
+                    .collect::<Vec<Input>>(),
+                theme::get_color(ColorItemNames::UnselectedInputs, &app.theme_mode),
+                &app.tolerance,
             );
-            services::draw::draw_apollonius_circles_pair(
+            draw::draw_inputs(
                 ui,
-                &apollonius_pair_3,
-                app.display_options.show_apollonius_circle_3,
-                services::theme::get_color(ColorItemNames::InversePoles3, &app.theme_mode),
+                &selected
+                    .into_iter()
+                    .map(|(_, input)| *input)
+                    .collect::<Vec<Input>>(),
+                theme::get_color(ColorItemNames::InitialCircles, &app.theme_mode),
+                &app.tolerance,
             );
-            services::draw::draw_apollonius_circles_pair(
+            if let Some(idx) = app.selected_input {
+                draw::draw_selected_input(
+                    ui,
+                    &app.initial_circles.inputs[idx],
+                    theme::get_color(ColorItemNames::Selected, &app.theme_mode),
+                    &app.tolerance,
+                );
+            }
+            if let Some(construction) = &solutions.construction {
+                draw::draw_homothetic_centers(
+                    ui,
+                    &construction.homothetic_set,
+                    display_options.show_homothetic,
+                    theme::get_color(ColorItemNames::HomotheticCenters, &app.theme_mode),
+                );
+                draw::draw_radical_center(
+                    ui,
+                    &construction.radical_center,
+                    display_options.show_radical,
+                    theme::get_color(ColorItemNames::Radical, &app.theme_mode),
+                );
+                for (idx, poles_set) in construction.inverse_pole_sets.iter().enumerate() {
+                    let color = theme::get_color(PAIR_COLORS[idx], &app.theme_mode);
+                    draw::draw_homothetic_axis(
+                        ui,
+                        &construction.homothetic_axes[idx],
+                        display_options.show_homothetic_axes,
+                        color,
+                    );
+                    draw::draw_inverse_poles(
+                        ui,
+                        poles_set,
+                        display_options.show_inverse_poles,
+                        color,
+                    );
+                    draw::draw_pole_lines(
+                        ui,
+                        poles_set,
+                        &construction.radical_center,
+                        display_options.show_pole_lines,
+                        color,
+                    );
+                    draw::draw_touch_points(
+                        ui,
+                        poles_set,
+                        display_options.show_touch_points,
+                        color,
+                    );
+                }
+            }
+            // Traces outlive their solution, so the path stays visible once the branch vanishes
+            for trace in app.traces.values() {
+                draw::draw_locus(
+                    ui,
+                    &trace.locus,
+                    theme::get_color(
+                        ColorItemNames::from_pair_index(trace.pair_index),
+                        &app.theme_mode,
+                    ),
+                );
+            }
+            for tracked_solution in &tracked {
+                let solution = &tracked_solution.solution;
+                // Circles that miss an input are still drawn, but stand out. The others keep
+                // the color of their branch while they are dragged around.
+                let color_name = if solution.is_tangent(&app.tolerance) {
+                    ColorItemNames::from_pair_index(tracked_solution.pair_index)
+                } else {
+                    ColorItemNames::Flagged
+                };
+                draw::draw_apollonius_circle(
+                    ui,
+                    &solution.circle,
+                    display_options.show_signatures[solution.signature.index()],
+                    theme::get_color(color_name, &app.theme_mode),
+                );
+            }
+            for solution in &other_solutions {
+                draw::draw_apollonius_circle(
+                    ui,
+                    &solution.circle,
+                    display_options.show_signatures[solution.signature.index()],
+                    theme::get_color(
+                        ColorItemNames::from_pair_index(solution.signature.pair_index()),
+                        &app.theme_mode,
+                    )
+                    .gamma_multiply(0.5),
+                );
+            }
+            draw::draw_algebraic_circles(
                 ui,
-                &apollonius_pair_4,
-                app.display_options.show_apollonius_circle_4,
-                services::theme::get_color(ColorItemNames::InversePoles4, &app.theme_mode),
+                &solutions.algebraic_circles,
+                display_options.show_algebraic,
+                theme::get_color(ColorItemNames::Algebraic, &app.theme_mode),
             );
         });
+
+        egui::Area::new(egui::Id::new("solution_report"))
+            .fixed_pos(ui.max_rect().left_top() + egui::vec2(8.0, 8.0))
+            .show(ui.ctx(), |ui| {
+                let found = solutions.tangent_circles.len();
+                let count = match solutions.configuration.expected_count() {
+                    Some(expected) => format!("{found} of {expected} solutions"),
+                    None => format!("{found} solutions"),
+                };
+                let report = format!(
+                    "{}: {count} by {}, max residual {:.1e}",
+                    solutions.configuration.class.value(),
+                    solutions.method.value().to_lowercase(),
+                    solutions.max_residual()
+                );
+                if solutions.has_expected_count() {
+                    ui.label(report);
+                } else {
+                    ui.colored_label(
+                        theme::get_color(ColorItemNames::Flagged, &app.theme_mode),
+                        report,
+                    );
+                }
+                let flagged = solutions.get_flagged(&app.tolerance);
+                if !flagged.is_empty() {
+                    ui.colored_label(
+                        theme::get_color(ColorItemNames::Flagged, &app.theme_mode),
+                        format!("{} not tangent within tolerance", flagged.len()),
+                    );
+                }
+                ui.collapsing("Residuals", |ui| {
+                    for solution in &solutions.tangent_circles {
+                        let text =
+                            format!("{} {:.1e}", solution.signature.label(), solution.residual);
+                        let label = if solution.is_tangent(&app.tolerance) {
+                            ui.label(text)
+                        } else {
+                            ui.colored_label(
+                                theme::get_color(ColorItemNames::Flagged, &app.theme_mode),
+                                text,
+                            )
+                        };
+                        label.on_hover_text(solution.signature.description());
+                    }
+                });
+
+                // Tell which construction pairs failed and why, the algebraic circles stand in
+                if let Some(construction) = &solutions.construction {
+                    for (idx, error) in construction.get_errors().iter().enumerate() {
+                        if let Some(error) = error {
+                            ui.colored_label(
+                                theme::get_color(PAIR_COLORS[idx], &app.theme_mode),
+                                format!("Pair {}: {}", idx + 1, error),
+                            );
+                        }
+                    }
+                }
+            });
+
+        if let Some(step) = app.construction_step {
+            egui::Area::new(egui::Id::new("construction_caption"))
+                .fixed_pos(ui.max_rect().left_bottom() + egui::vec2(8.0, -8.0))
+                .pivot(egui::Align2::LEFT_BOTTOM)
+                .show(ui.ctx(), |ui| {
+                    ui.set_max_width(ui.ctx().screen_rect().width() / 3.0);
+                    ui.strong(step.value());
+                    if solutions.construction.is_some() {
+                        ui.label(step.caption());
+                    } else {
+                        ui.label("The construction needs three circles.");
+                    }
+                });
+        }
     });
 }
 
-fn handle_circles_drag_events(
-    response: egui::Response,
-    initial_circles: &mut InitialCircles,
-    is_dragging: &mut Dragging,
-) {
-    if response.drag_started() {
-        let mut closest: Option<Dragging> = None;
-        let mut min_distance = f32::INFINITY;
-
-        if let Some(pos) = response.interact_pointer_pos() {
-            for (dragging, circle) in [
-                (Dragging::Circle1, &initial_circles.circle_1),
-                (Dragging::Circle2, &initial_circles.circle_2),
-                (Dragging::Circle3, &initial_circles.circle_3),
-            ] {
+// Solutions of the triples not fed to the construction
+fn solve_other_triples(initial_circles: &InitialCircles, tolerance: &Tolerance) -> Vec<Solution> {
+    let mut selected = initial_circles.selected;
+    selected.sort_unstable();
+
+    initial_circles
+        .triples()
+        .into_iter()
+        .filter(|triple| *triple != selected)
+        .flat_map(|triple| {
+            let inputs = triple.map(|idx| initial_circles.inputs[idx]);
+            apollonius::solve(&inputs, tolerance).tangent_circles
+        })
+        .collect()
+}
+
+// What a drag starting at this position grabs, the closest candidate winning. The rim is a thin
+// stroke, its grab band is kept at the same width on screen whatever the zoom.
+fn find_drag_target(pos: Point, inputs: &[Input], zoom: f32) -> Option<Dragging> {
+    let rim_band = draw::POINT_RADIUS / f64::from(zoom);
+    let mut closest: Option<Dragging> = None;
+    let mut min_distance = f64::INFINITY;
+
+    for (idx, input) in inputs.iter().enumerate() {
+        let candidates: Vec<(Dragging, f64)> = match input {
+            Input::Circle(circle) => {
                 let dist = pos.distance(circle.center);
-                if dist < circle.radius && dist < min_distance {
-                    min_distance = dist;
-                    closest = Some(dragging);
-                }
+                let rim_dist = (dist - circle.radius).abs();
+                // Points have no rim, they can only be moved
+                let rim = (circle.radius > draw::POINT_RADIUS && rim_dist < rim_band)
+                    .then_some((Dragging::Rim(idx), rim_dist));
+                let inside = (dist < circle.radius.max(draw::POINT_RADIUS))
+                    .then_some((Dragging::Input(idx), dist));
+                rim.into_iter().chain(inside).collect()
             }
-            if let Some(dragging) = closest {
-                *is_dragging = dragging;
+            // Handles win over the line itself so they stay reachable
+            Input::Line(segment) => [segment.0, segment.1]
+                .iter()
+                .enumerate()
+                .map(|(handle, point)| {
+                    (
+                        Dragging::Handle(idx, handle),
+                        pos.distance(*point) - draw::POINT_RADIUS,
+                    )
+                })
+                .chain([(Dragging::Input(idx), segment.signed_distance(pos).abs())])
+                .filter(|(_, dist)| *dist < draw::POINT_RADIUS)
+                .collect(),
+        };
+        for (dragging, dist) in candidates {
+            if dist < min_distance {
+                min_distance = dist;
+                closest = Some(dragging);
             }
         }
     }
 
+    closest
+}
+
+fn handle_circles_drag_events(
+    response: &egui::Response,
+    initial_circles: &mut InitialCircles,
+    is_dragging: &mut Dragging,
+    zoom: f32,
+    modifiers: egui::Modifiers,
+) {
+    if response.drag_started()
+        && let Some(dragging) = response
+            .interact_pointer_pos()
+            .and_then(|pos| find_drag_target(draw::to_point(pos), &initial_circles.inputs, zoom))
+    {
+        *is_dragging = dragging;
+    }
+
     if response.dragged() {
-        match is_dragging {
-            Dragging::Circle1 => {
-                initial_circles.circle_1.center += response.drag_delta();
-            }
-            Dragging::Circle2 => {
-                initial_circles.circle_2.center += response.drag_delta();
-            }
-            Dragging::Circle3 => {
-                initial_circles.circle_3.center += response.drag_delta();
+        let delta = draw::to_vector(response.drag_delta());
+        match *is_dragging {
+            Dragging::Input(idx) => initial_circles.get_mut(idx).translate(delta),
+            Dragging::Handle(idx, handle) => {
+                if let Input::Line(segment) = initial_circles.get_mut(idx) {
+                    if handle == 0 {
+                        segment.0 += delta;
+                    } else {
+                        segment.1 += delta;
+                    }
+                }
             }
-            Dragging::None => {
-                for circle in initial_circles.as_array().iter_mut() {
-                    circle.center += response.drag_delta();
+            Dragging::Rim(idx) => {
+                if let (Input::Circle(circle), Some(pos)) = (
+                    initial_circles.get_mut(idx),
+                    response.interact_pointer_pos(),
+                ) {
+                    resize_circle(circle, draw::to_point(pos), delta, modifiers);
                 }
             }
+            // Empty space, the scene pans instead
+            Dragging::None => {}
         }
     }
 
@@ -214,3 +399,41 @@ fn handle_circles_drag_events(
         *is_dragging = Dragging::None;
     }
 }
+
+// The grabbed rim point follows the pointer. Shift keeps the center where it is, Alt keeps
+// the opposite edge instead.
+fn resize_circle(circle: &mut Circle, pos: Point, delta: Vector, modifiers: egui::Modifiers) {
+    let previous = pos - delta;
+    let outward = previous - circle.center;
+    let length = outward.length();
+    if length == 0.0 {
+        return;
+    }
+    let direction = outward * (1.0 / length);
+
+    if modifiers.alt {
+        let opposite = circle.center - direction * circle.radius;
+        circle.center = opposite + (pos - opposite) * 0.5;
+        circle.radius = pos.distance(opposite) / 2.0;
+    } else if modifiers.shift {
+        circle.radius = pos.distance(circle.center);
+    } else {
+        let radial = delta.dot(direction);
+        circle.center += delta - direction * radial;
+        circle.radius = (circle.radius + radial).max(0.0);
+    }
+}
+
+// Double arrow closest to the direction from the center, y pointing down on screen
+fn resize_cursor(outward: Vector) -> egui::CursorIcon {
+    let angle = outward.y.atan2(outward.x).to_degrees().rem_euclid(180.0);
+    if !(22.5..157.5).contains(&angle) {
+        egui::CursorIcon::ResizeHorizontal
+    } else if angle < 67.5 {
+        egui::CursorIcon::ResizeNwSe
+    } else if angle < 112.5 {
+        egui::CursorIcon::ResizeVertical
+    } else {
+        egui::CursorIcon::ResizeNeSw
+    }
+}
@@ -0,0 +1,61 @@
+use apollonius::models::{input::Input, point::Point};
+use egui::{Context, DragValue, Ui};
+
+use crate::app::MyApp;
+
+// Scene units per dragged pixel for coordinates
+const COORDINATE_SPEED: f64 = 0.1;
+
+pub fn get(app: &mut MyApp, ctx: &Context) {
+    if !app.panels.show_inputs {
+        return;
+    }
+
+    egui::SidePanel::left("inputs_panel")
+        .frame(egui::Frame::side_top_panel(&ctx.style()).inner_margin(8.0))
+        .show(ctx, |ui| {
+            ui.heading("Inputs");
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                egui::Grid::new("inputs_grid").show(ui, |ui| {
+                    for (idx, input) in app.initial_circles.inputs.iter_mut().enumerate() {
+                        ui.label(format!("Input {}", idx + 1));
+                        match input {
+                            Input::Circle(circle) => {
+                                point_fields(ui, &mut circle.center);
+                                let speed = radius_speed(circle.radius);
+                                ui.add(
+                                    DragValue::new(&mut circle.radius)
+                                        .prefix("r: ")
+                                        .speed(speed)
+                                        .range(0.0..=f64::INFINITY),
+                                );
+                            }
+                            Input::Line(segment) => {
+                                point_fields(ui, &mut segment.0);
+                                point_fields(ui, &mut segment.1);
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+            });
+        });
+}
+
+fn point_fields(ui: &mut Ui, point: &mut Point) {
+    ui.add(
+        DragValue::new(&mut point.x)
+            .prefix("x: ")
+            .speed(COORDINATE_SPEED),
+    );
+    ui.add(
+        DragValue::new(&mut point.y)
+            .prefix("y: ")
+            .speed(COORDINATE_SPEED),
+    );
+}
+
+// Proportional to the radius, so dragging scales it by the same factor at any size
+fn radius_speed(radius: f64) -> f64 {
+    radius.max(1.0) * 0.01
+}
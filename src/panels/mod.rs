@@ -1,3 +1,6 @@
 pub mod bottom_panel;
 pub mod central_panel;
+pub mod inputs_panel;
+pub mod settings_panel;
 pub mod top_panel;
+pub mod traces_panel;
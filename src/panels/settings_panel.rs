@@ -0,0 +1,55 @@
+use std::ops::RangeInclusive;
+
+use apollonius::models::tolerance::Tolerance;
+use egui::{Context, DragValue, Slider};
+
+use crate::app::MyApp;
+
+pub fn get(app: &mut MyApp, ctx: &Context) {
+    if !app.panels.show_settings {
+        return;
+    }
+
+    egui::SidePanel::right("settings_panel")
+        .frame(egui::Frame::side_top_panel(&ctx.style()).inner_margin(8.0))
+        .show(ctx, |ui| {
+            const ABSOLUTE_RANGE: RangeInclusive<f64> = 1e-12..=1e-1;
+            const RELATIVE_RANGE: RangeInclusive<f64> = 1e-15..=1e-3;
+            ui.heading("Tolerance");
+            ui.add(
+                Slider::new(&mut app.tolerance.absolute, ABSOLUTE_RANGE)
+                    .logarithmic(true)
+                    .custom_formatter(|value, _| format!("{value:.0e}"))
+                    .text("Absolute"),
+            )
+            .on_hover_text("Distances below this count as zero, in scene units");
+            ui.add(
+                Slider::new(&mut app.tolerance.relative, RELATIVE_RANGE)
+                    .logarithmic(true)
+                    .custom_formatter(|value, _| format!("{value:.0e}"))
+                    .text("Relative"),
+            )
+            .on_hover_text("Differences below this fraction of the values compared count as zero");
+            if ui.add(egui::Button::new("Reset tolerance")).clicked() {
+                app.tolerance = Tolerance::default();
+            }
+
+            ui.separator();
+            ui.heading("Keyboard");
+            ui.label("Tab selects the next input, arrows move it, + and - change its radius");
+            ui.add(
+                DragValue::new(&mut app.keyboard_steps.nudge)
+                    .prefix("Arrow step: ")
+                    .speed(0.1)
+                    .range(0.0..=f64::INFINITY),
+            )
+            .on_hover_text("Ten times as far with Shift");
+            ui.add(
+                DragValue::new(&mut app.keyboard_steps.radius)
+                    .prefix("Radius step: ")
+                    .speed(0.1)
+                    .range(0.0..=f64::INFINITY),
+            )
+            .on_hover_text("The same with or without Shift, which + already needs on most layouts");
+        });
+}
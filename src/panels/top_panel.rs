@@ -1,21 +1,87 @@
-use crate::{enums::theme_mode::ThemeMode, models::app::MyApp};
+use std::path::PathBuf;
+
+use apollonius::models::tangency::TangencySignature;
+
+use crate::{
+    app::MyApp,
+    enums::{construction_step::ConstructionStep, theme_mode::ThemeMode},
+};
 
 pub fn get(app: &mut MyApp, ctx: &egui::Context) {
     egui::TopBottomPanel::top("top_control_panel")
         .frame(egui::Frame::side_top_panel(&ctx.style()).inner_margin(8.0))
         .show(ctx, |ui| {
             ui.horizontal_wrapped(|ui| {
-                ui.checkbox(
-                    &mut app.display_options.show_homothetic,
-                    "Homothetic centers",
-                );
-                ui.checkbox(&mut app.display_options.show_radical, "Radical center");
-                ui.checkbox(&mut app.display_options.show_inverse_poles, "Inverse poles");
-                ui.checkbox(&mut app.display_options.show_apollonius_circle_1, "A1");
-                ui.checkbox(&mut app.display_options.show_apollonius_circle_2, "A2");
-                ui.checkbox(&mut app.display_options.show_apollonius_circle_3, "A3");
-                ui.checkbox(&mut app.display_options.show_apollonius_circle_4, "A4");
+                ui.menu_button("Scene", |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label("File");
+                        ui.text_edit_singleline(&mut app.scene_path);
+                    });
+                    let path = PathBuf::from(&app.scene_path);
+                    if ui.button("Save").clicked() {
+                        app.save_scene(&path);
+                        ui.close_menu();
+                    }
+                    if ui.button("Load").clicked() {
+                        app.load_scene(&path);
+                        ui.close_menu();
+                    }
+                });
+                if let Some(status) = &app.scene_status {
+                    ui.label(status);
+                }
+                ui.separator();
+                // The construction mode picks what to show by itself
+                ui.add_enabled_ui(app.construction_step.is_none(), |ui| {
+                    ui.checkbox(
+                        &mut app.display_options.show_homothetic,
+                        "Homothetic centers",
+                    );
+                    ui.checkbox(
+                        &mut app.display_options.show_homothetic_axes,
+                        "Homothetic axes",
+                    );
+                    ui.checkbox(&mut app.display_options.show_radical, "Radical center");
+                    ui.checkbox(&mut app.display_options.show_inverse_poles, "Inverse poles");
+                    ui.checkbox(&mut app.display_options.show_pole_lines, "Pole lines");
+                    ui.checkbox(&mut app.display_options.show_touch_points, "Touch points");
+                    ui.checkbox(&mut app.display_options.show_algebraic, "Algebraic");
+                    ui.separator();
+                    ui.label("Tangency");
+                    for signature in TangencySignature::all() {
+                        ui.checkbox(
+                            &mut app.display_options.show_signatures[signature.index()],
+                            signature.label(),
+                        )
+                        .on_hover_text(signature.description());
+                    }
+                });
+                ui.separator();
+                let mut construction_mode = app.construction_step.is_some();
+                if ui
+                    .toggle_value(&mut construction_mode, "Construction")
+                    .changed()
+                {
+                    app.construction_step = construction_mode.then_some(ConstructionStep::Inputs);
+                }
+                if let Some(step) = app.construction_step {
+                    if ui.button("Previous").clicked() {
+                        app.construction_step = Some(step.previous());
+                    }
+                    ui.label(format!(
+                        "{}/{} {}",
+                        step.index() + 1,
+                        ConstructionStep::as_vec().len(),
+                        step.value()
+                    ));
+                    if ui.button("Next").clicked() {
+                        app.construction_step = Some(step.next());
+                    }
+                }
                 ui.separator();
+                ui.toggle_value(&mut app.panels.show_inputs, "Inputs");
+                ui.toggle_value(&mut app.panels.show_traces, "Traces");
+                ui.toggle_value(&mut app.panels.show_settings, "Settings");
                 egui::ComboBox::from_label("Theme")
                     .selected_text(format!("{:?}", app.theme_mode))
                     .show_ui(ui, |ui| {
@@ -0,0 +1,78 @@
+use apollonius::models::locus::Locus;
+use egui::Context;
+
+use crate::{
+    app::{MyApp, Trace},
+    enums::color_item_names::ColorItemNames,
+    theme,
+};
+
+pub fn get(app: &mut MyApp, ctx: &Context) {
+    if !app.panels.show_traces {
+        return;
+    }
+
+    egui::SidePanel::left("traces_panel")
+        .frame(egui::Frame::side_top_panel(&ctx.style()).inner_margin(8.0))
+        .show(ctx, |ui| {
+            ui.heading("Traces");
+            egui::Grid::new("traces_grid").show(ui, |ui| {
+                for tracked_solution in &app.solution_tracker.tracked {
+                    let id = tracked_solution.id;
+                    ui.colored_label(
+                        theme::get_color(
+                            ColorItemNames::from_pair_index(tracked_solution.pair_index),
+                            &app.theme_mode,
+                        ),
+                        format!("#{id} {}", tracked_solution.solution.signature.label()),
+                    )
+                    .on_hover_text(tracked_solution.solution.signature.description());
+
+                    let mut is_traced = app.traces.contains_key(&id);
+                    if ui.checkbox(&mut is_traced, "Trace").changed() {
+                        if is_traced {
+                            app.traces.insert(
+                                id,
+                                Trace {
+                                    pair_index: tracked_solution.pair_index,
+                                    locus: Locus::default(),
+                                },
+                            );
+                        } else {
+                            app.traces.remove(&id);
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+            ui.separator();
+
+            // Traces of vanished solutions stay listed here until they are removed
+            let mut ids: Vec<usize> = app.traces.keys().copied().collect();
+            ids.sort_unstable();
+            for id in ids {
+                ui.horizontal(|ui| {
+                    let trace = app.traces.get_mut(&id).unwrap();
+                    ui.label(format!("#{id}: {} points", trace.locus.points.len()));
+                    if ui.button("Clear").clicked() {
+                        trace.locus.clear();
+                    }
+                    if ui
+                        .button("Copy CSV")
+                        .on_hover_text("Copy the traced centers to the clipboard")
+                        .clicked()
+                    {
+                        ui.ctx().copy_text(trace.locus.to_csv());
+                    }
+                    if ui.button("Remove").clicked() {
+                        app.traces.remove(&id);
+                    }
+                });
+            }
+            if !app.traces.is_empty() && ui.button("Clear all").clicked() {
+                for trace in app.traces.values_mut() {
+                    trace.locus.clear();
+                }
+            }
+        });
+}
@@ -0,0 +1,238 @@
+use crate::models::{circle::Circle, input::Input, point::Point, tolerance::Tolerance};
+
+type Vec3 = [f64; 3];
+
+// +1 is an external tangency to the matching input, -1 an internal one
+pub const SIGN_COMBINATIONS: [[f64; 3]; 8] = [
+    [1.0, 1.0, 1.0],
+    [1.0, 1.0, -1.0],
+    [1.0, -1.0, 1.0],
+    [1.0, -1.0, -1.0],
+    [-1.0, 1.0, 1.0],
+    [-1.0, 1.0, -1.0],
+    [-1.0, -1.0, 1.0],
+    [-1.0, -1.0, -1.0],
+];
+
+pub fn solve_apollonius_algebraic(inputs: &[Input; 3], tolerance: &Tolerance) -> Vec<Circle> {
+    let mut solutions: Vec<Circle> = Vec::new();
+    for signs in SIGN_COMBINATIONS {
+        for circle in solve_sign_combination(inputs, signs, tolerance) {
+            if !solutions
+                .iter()
+                .any(|other| is_same_circle(other, &circle, tolerance))
+            {
+                solutions.push(circle);
+            }
+        }
+    }
+
+    solutions
+}
+
+// Every input gives one equation in the unknown center (x, y) and radius r:
+//   circle i: (x - xi)^2 + (y - yi)^2 = (r + si * ri)^2
+//   line i:   signed distance of (x, y) to the line = si * r
+// Only the roots with r > 0 are kept, a negative root belongs to the opposite sign combination.
+pub fn solve_sign_combination(
+    inputs: &[Input; 3],
+    signs: [f64; 3],
+    tolerance: &Tolerance,
+) -> Vec<Circle> {
+    let circles: Vec<(Circle, f64)> = inputs
+        .iter()
+        .zip(signs)
+        .filter_map(|(input, sign)| input.as_circle().map(|circle| (circle, sign)))
+        .collect();
+
+    // Work relative to the first center so its equation has no linear x/y terms
+    let origin: Point = match circles.first() {
+        Some((circle, _)) => circle.center,
+        None => inputs[0].center(),
+    };
+
+    // Lines are planes in (x, y, r) already
+    let mut planes: Vec<(Vec3, f64)> = inputs
+        .iter()
+        .zip(signs)
+        .filter_map(|(input, sign)| match input {
+            Input::Line(segment) => {
+                let direction = segment.1 - segment.0;
+                let normal = direction.rot90() * (1.0 / direction.length());
+                Some(([normal.x, normal.y, -sign], normal.dot(segment.0 - origin)))
+            }
+            Input::Circle(_) => None,
+        })
+        .collect();
+
+    // So are the differences between the first circle's equation and the other ones
+    let signed_radius = |(circle, sign): &(Circle, f64)| sign * circle.radius;
+    let power = |(circle, _): &(Circle, f64)| {
+        let offset = circle.center - origin;
+        offset.dot(offset) - circle.radius.powi(2)
+    };
+    if let Some(first) = circles.first() {
+        for other in &circles[1..] {
+            let offset = other.0.center - origin;
+            planes.push((
+                [
+                    2.0 * offset.x,
+                    2.0 * offset.y,
+                    2.0 * (signed_radius(other) - signed_radius(first)),
+                ],
+                power(other) - power(first),
+            ));
+        }
+    }
+
+    let candidates: Vec<Vec3> = match circles.first() {
+        // Three lines, the system is linear
+        None => intersect_three_planes(planes[0], planes[1], planes[2], tolerance)
+            .into_iter()
+            .collect(),
+        Some(first) => {
+            let Some((base, direction)) = intersect_planes(planes[0], planes[1], tolerance) else {
+                return vec![];
+            };
+
+            // The first circle's equation along base + t * direction
+            let r0 = signed_radius(first);
+            let a = direction[0].powi(2) + direction[1].powi(2) - direction[2].powi(2);
+            let b = 2.0
+                * (base[0] * direction[0] + base[1] * direction[1] - base[2] * direction[2])
+                - 2.0 * r0 * direction[2];
+            let c = base[0].powi(2) + base[1].powi(2)
+                - base[2].powi(2)
+                - 2.0 * r0 * base[2]
+                - r0.powi(2);
+
+            let offset = dot3(base, base).sqrt();
+            solve_quadratic(a, b, c, dot3(direction, direction), offset, tolerance)
+                .into_iter()
+                .map(|t| {
+                    [
+                        base[0] + t * direction[0],
+                        base[1] + t * direction[1],
+                        base[2] + t * direction[2],
+                    ]
+                })
+                .collect()
+        }
+    };
+
+    candidates
+        .into_iter()
+        .map(|[x, y, r]| Circle {
+            center: Point {
+                x: origin.x + x,
+                y: origin.y + y,
+            },
+            radius: r,
+        })
+        .filter(|circle| {
+            circle.radius > 0.0 && circle.center.x.is_finite() && circle.center.y.is_finite()
+        })
+        .collect()
+}
+
+fn dot3(u: Vec3, v: Vec3) -> f64 {
+    u[0] * v[0] + u[1] * v[1] + u[2] * v[2]
+}
+
+fn cross3(u: Vec3, v: Vec3) -> Vec3 {
+    [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ]
+}
+
+// Line of intersection of the planes n1 . p = d1 and n2 . p = d2 as (point, direction)
+fn intersect_planes(
+    (n1, d1): (Vec3, f64),
+    (n2, d2): (Vec3, f64),
+    tolerance: &Tolerance,
+) -> Option<(Vec3, Vec3)> {
+    let direction = cross3(n1, n2);
+    let denominator = dot3(direction, direction);
+    if tolerance.is_zero(denominator.sqrt(), (dot3(n1, n1) * dot3(n2, n2)).sqrt()) {
+        return None;
+    }
+
+    let n1n2 = dot3(n1, n2);
+    let s = (d1 * dot3(n2, n2) - d2 * n1n2) / denominator;
+    let t = (d2 * dot3(n1, n1) - d1 * n1n2) / denominator;
+    let base = [
+        s * n1[0] + t * n2[0],
+        s * n1[1] + t * n2[1],
+        s * n1[2] + t * n2[2],
+    ];
+
+    Some((base, direction))
+}
+
+// Cramer's rule on the three planes n . p = d
+fn intersect_three_planes(
+    (n1, d1): (Vec3, f64),
+    (n2, d2): (Vec3, f64),
+    (n3, d3): (Vec3, f64),
+    tolerance: &Tolerance,
+) -> Option<Vec3> {
+    let n2n3 = cross3(n2, n3);
+    let n3n1 = cross3(n3, n1);
+    let n1n2 = cross3(n1, n2);
+    let determinant = dot3(n1, n2n3);
+    let scale = (dot3(n1, n1) * dot3(n2, n2) * dot3(n3, n3)).sqrt();
+    if tolerance.is_zero(determinant, scale) {
+        return None;
+    }
+
+    Some(std::array::from_fn(|idx| {
+        (d1 * n2n3[idx] + d2 * n3n1[idx] + d3 * n1n2[idx]) / determinant
+    }))
+}
+
+// `scale` is the size a has to be compared against for the equation to count as linear. Both
+// are squared lengths per unit of t, so the absolute tolerance in scene units does not apply.
+// `offset` is how far t = 0 lies from the origin in (x, y, r).
+fn solve_quadratic(
+    a: f64,
+    b: f64,
+    c: f64,
+    scale: f64,
+    offset: f64,
+    tolerance: &Tolerance,
+) -> Vec<f64> {
+    if a.abs() <= tolerance.relative * scale {
+        if b == 0.0 {
+            return vec![];
+        }
+        return vec![-c / b];
+    }
+
+    // A double root comes out of rounding as two roots about sqrt(eps) apart, or none at all.
+    // The gap between them is measured in scene units along the line, where t counts
+    // sqrt(scale), and since rounding the coefficients moves the roots by the square root of
+    // the error, it is held against the square root of the relative tolerance.
+    let discriminant = b.powi(2) - 4.0 * a * c;
+    let length = scale.sqrt();
+    let half_gap = discriminant.abs().sqrt() / (2.0 * a).abs() * length;
+    let size = offset + (b / (2.0 * a)).abs() * length;
+    if Tolerance::new(tolerance.absolute, tolerance.relative.sqrt()).is_zero(half_gap, size) {
+        return vec![-b / (2.0 * a)];
+    }
+    if discriminant < 0.0 {
+        return vec![];
+    }
+
+    // Avoids the cancellation of -b + sqrt(discriminant) when b dominates
+    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+    if q == 0.0 {
+        return vec![0.0];
+    }
+    vec![q / a, c / q]
+}
+
+fn is_same_circle(c1: &Circle, c2: &Circle, tolerance: &Tolerance) -> bool {
+    tolerance.is_same_point(c1.center, c2.center) && tolerance.is_equal(c1.radius, c2.radius)
+}
@@ -1,47 +1,56 @@
-use core::f32;
-
-use eframe::egui;
-use egui::Pos2;
-
-use crate::models::{circle::Circle, segment::Segment, straightline::StraightLine};
+use crate::models::{
+    circle::Circle, geometry_error::GeometryError, point::Point, segment::Segment,
+    straightline::StraightLine, tolerance::Tolerance,
+};
 
 pub fn get_radical_axis(c1: Circle, c2: Circle) -> StraightLine {
-    let a: f32 = 2.0 * (c2.center.x - c1.center.x);
-    let b: f32 = 2.0 * (c2.center.y - c1.center.y);
-    let c: f32 = (c1.center.x.powi(2) + c1.center.y.powi(2) - c1.radius.powi(2))
+    let a: f64 = 2.0 * (c2.center.x - c1.center.x);
+    let b: f64 = 2.0 * (c2.center.y - c1.center.y);
+    let c: f64 = (c1.center.x.powi(2) + c1.center.y.powi(2) - c1.radius.powi(2))
         - (c2.center.x.powi(2) + c2.center.y.powi(2) - c2.radius.powi(2));
 
     StraightLine { a, b, c }
 }
 
-pub fn internal_division_ratio(p1: Pos2, p2: Pos2, ratio_s: f32, ratio_t: f32) -> Option<Pos2> {
-    if p1.distance(p2) < 1e-6 {
-        return None;
+pub fn internal_division_ratio(
+    p1: Point,
+    p2: Point,
+    ratio_s: f64,
+    ratio_t: f64,
+    tolerance: &Tolerance,
+) -> Result<Point, GeometryError> {
+    if tolerance.is_same_point(p1, p2) {
+        return Err(GeometryError::CoincidentPoints);
     }
 
-    if ratio_s < 1e-6 {
-        if ratio_t < 1e-6 { None } else { Some(p1) }
+    let ratio_scale = ratio_s.max(ratio_t);
+    if tolerance.is_zero(ratio_s, ratio_scale) {
+        if tolerance.is_zero(ratio_t, ratio_scale) {
+            Err(GeometryError::CoincidentPoints)
+        } else {
+            Ok(p1)
+        }
     } else {
-        if ratio_t < 1e-6 {
-            return Some(p2);
+        if tolerance.is_zero(ratio_t, ratio_scale) {
+            return Ok(p2);
         }
         let s: Segment = Segment(p1, p2);
         let denominator = ratio_s + ratio_t;
         let x = (ratio_t * s.0.x + ratio_s * s.1.x) / denominator;
         let y = (ratio_t * s.0.y + ratio_s * s.1.y) / denominator;
-        Some(Pos2 { x, y })
+        Ok(Point { x, y })
     }
 }
 
-pub fn check_if_flat_angle(s1: &Segment, s2: &Segment) -> bool {
-    let angle_1: f32 = angle(s1, s2).abs();
-    let angle_2: f32 = (angle(s1, s2) - 180.0).abs();
-    let angle_3: f32 = (angle(s1, s2) - 360.0).abs();
+// The cross product is the sine of the angle between the segments, scaled by both lengths
+pub fn check_if_flat_angle(s1: &Segment, s2: &Segment, tolerance: &Tolerance) -> bool {
+    let d1 = s1.1 - s1.0;
+    let d2 = s2.1 - s2.0;
 
-    angle_1 < 1e-6 || angle_2 < 1e-6 || angle_3 < 1e-6
+    tolerance.is_zero(d1.cross(d2), d1.length() * d2.length())
 }
 
-pub fn angle_between_0_360(mut angle: f32) -> f32 {
+pub fn angle_between_0_360(mut angle: f64) -> f64 {
     while angle < 0.0 {
         angle += 360.0;
     }
@@ -52,11 +61,11 @@ pub fn angle_between_0_360(mut angle: f32) -> f32 {
     angle
 }
 
-pub fn angle_of_vector(p1: Pos2, p2: Pos2) -> Option<f32> {
+pub fn angle_of_vector(p1: Point, p2: Point, tolerance: &Tolerance) -> Option<f64> {
     let x = p2.x - p1.x;
     let y = p2.y - p1.y;
 
-    if x.abs() < 1e-6 && y.abs() < 1e-6 {
+    if tolerance.is_same_point(p1, p2) {
         return None;
     }
 
@@ -68,100 +77,136 @@ pub fn angle_of_vector(p1: Pos2, p2: Pos2) -> Option<f32> {
     Some(angle)
 }
 
-pub fn angle(s1: &Segment, s2: &Segment) -> f32 {
-    let a: Pos2 = Pos2 {
+pub fn angle(s1: &Segment, s2: &Segment, tolerance: &Tolerance) -> f64 {
+    let a: Point = Point {
         x: s1.0.x,
         y: s1.0.y,
     };
-    let b: Pos2 = Pos2 {
+    let b: Point = Point {
         x: s1.1.x,
         y: s1.1.y,
     };
-    let c: Pos2 = Pos2 {
+    let c: Point = Point {
         x: s2.0.x,
         y: s2.0.y,
     };
-    let d: Pos2 = Pos2 {
+    let d: Point = Point {
         x: s2.1.x,
         y: s2.1.y,
     };
 
-    let angle_1: f32 = angle_of_vector(a, b).unwrap_or(-10000.0);
-    let angle_2: f32 = angle_of_vector(c, d).unwrap_or(-10000.0);
+    let angle_1: f64 = angle_of_vector(a, b, tolerance).unwrap_or(-10000.0);
+    let angle_2: f64 = angle_of_vector(c, d, tolerance).unwrap_or(-10000.0);
 
     angle_between_0_360(angle_2 - angle_1)
 }
 
-pub fn find_intersection(l1: &StraightLine, l2: &StraightLine) -> Pos2 {
+pub fn find_intersection(
+    l1: &StraightLine,
+    l2: &StraightLine,
+    tolerance: &Tolerance,
+) -> Result<Point, GeometryError> {
     let a1 = l1.a;
     let b1 = l1.b;
     let c1 = l1.c;
     let a2 = l2.a;
     let b2 = l2.b;
     let c2 = l2.c;
-    Pos2 {
-        x: (b1 * c2 - b2 * c1) / (a1 * b2 - a2 * b1),
-        y: (a2 * c1 - a1 * c2) / (a1 * b2 - a2 * b1),
+
+    let norm_1 = a1.hypot(b1);
+    let norm_2 = a2.hypot(b2);
+    if tolerance.is_zero(norm_1, 0.0) || tolerance.is_zero(norm_2, 0.0) {
+        return Err(GeometryError::DegenerateLine);
     }
+    // The determinant is the sine of the angle between the lines, scaled by both normals
+    let determinant = a1 * b2 - a2 * b1;
+    if tolerance.is_zero(determinant, norm_1 * norm_2) {
+        return Err(GeometryError::ParallelLines);
+    }
+
+    Ok(Point {
+        x: (b1 * c2 - b2 * c1) / determinant,
+        y: (a2 * c1 - a1 * c2) / determinant,
+    })
 }
 
-pub fn is_segment_intersecting_circle(s: &Segment, c: &Circle) -> bool {
-    c.radius >= calculate_distance(&s.as_straight_line(), c.center)
+// Tangent segments count as intersecting
+pub fn is_segment_intersecting_circle(s: &Segment, c: &Circle, tolerance: &Tolerance) -> bool {
+    let distance = calculate_distance(&s.as_straight_line(), c.center);
+    distance <= c.radius || tolerance.is_equal(distance, c.radius)
 }
 
-pub fn calculate_distance(l: &StraightLine, p: Pos2) -> f32 {
+pub fn calculate_distance(l: &StraightLine, p: Point) -> f64 {
     ((l.a * p.x + l.b * p.y + l.c) / (l.a.powi(2) + l.b.powi(2)).sqrt()).abs()
 }
 
+// Half the chord cut by a line at `distance` from the center, tangent lines are let through
+fn half_chord(radius: f64, distance: f64, tolerance: &Tolerance) -> Result<f64, GeometryError> {
+    let squared = radius.powi(2) - distance.powi(2);
+    if squared < 0.0 && !tolerance.is_zero(squared, radius.powi(2)) {
+        return Err(GeometryError::NoIntersection);
+    }
+
+    Ok(squared.max(0.0).sqrt())
+}
+
 pub fn get_circle_straight_line_intersection(
-    l: &Option<StraightLine>,
+    strl: &StraightLine,
     c: &Circle,
-) -> Option<Segment> {
-    match l {
-        Some(strl) => {
-            let projection: Pos2 = find_projection(strl, c.center);
-            let length_a = calculate_distance(strl, c.center);
-            let length_b = (c.radius.powi(2) - length_a.powi(2)).sqrt();
-            let denominator = (strl.b.powi(2) + strl.a.powi(2)).sqrt();
-
-            Some(Segment(
-                Pos2 {
-                    x: projection.x + strl.b * length_b / denominator,
-                    y: projection.y - strl.a * length_b / denominator,
-                },
-                Pos2 {
-                    x: projection.x - strl.b * length_b / denominator,
-                    y: projection.y + strl.a * length_b / denominator,
-                },
-            ))
-        }
-        None => None,
-    }
+    tolerance: &Tolerance,
+) -> Result<Segment, GeometryError> {
+    let projection: Point = find_projection(strl, c.center, tolerance)?;
+    let length_a = calculate_distance(strl, c.center);
+    let length_b = half_chord(c.radius, length_a, tolerance)?;
+    let denominator = (strl.b.powi(2) + strl.a.powi(2)).sqrt();
+
+    Ok(Segment(
+        Point {
+            x: projection.x + strl.b * length_b / denominator,
+            y: projection.y - strl.a * length_b / denominator,
+        },
+        Point {
+            x: projection.x - strl.b * length_b / denominator,
+            y: projection.y + strl.a * length_b / denominator,
+        },
+    ))
 }
 
-pub fn get_circles_intersection(c1: &Circle, c2: &Circle) -> Segment {
+pub fn get_circles_intersection(
+    c1: &Circle,
+    c2: &Circle,
+    tolerance: &Tolerance,
+) -> Result<Segment, GeometryError> {
+    if tolerance.is_same_point(c1.center, c2.center) {
+        return Err(if tolerance.is_equal(c1.radius, c2.radius) {
+            GeometryError::CoincidentCircles
+        } else {
+            GeometryError::NoIntersection
+        });
+    }
+
     let t0: StraightLine = get_circles_intersection_as_straight_line(c1, c2);
     let t1: StraightLine = StraightLine {
         a: c2.center.y - c1.center.y,
         b: c1.center.x - c2.center.x,
         c: c1.center.y * c2.center.x - c1.center.x * c2.center.y,
     };
-    let intersection_t0_t1: Pos2 = find_intersection(&t0, &t1);
+    let intersection_t0_t1: Point = find_intersection(&t0, &t1, tolerance)?;
 
     let length_a = calculate_distance(&t0, c1.center);
-    let length_b = (c1.radius.powi(2) - length_a.powi(2)).sqrt();
+    let length_b = half_chord(c1.radius, length_a, tolerance)?;
     let denominator = (t0.b.powi(2) + t0.a.powi(2)).sqrt();
 
-    Segment(
-        Pos2 {
+    Ok(Segment(
+        Point {
             x: intersection_t0_t1.x + t0.b * length_b / denominator,
             y: intersection_t0_t1.y - t0.a * length_b / denominator,
         },
-        Pos2 {
+        Point {
             x: intersection_t0_t1.x - t0.b * length_b / denominator,
             y: intersection_t0_t1.y + t0.a * length_b / denominator,
         },
-    )
+    ))
 }
 
 pub fn get_circles_intersection_as_straight_line(c1: &Circle, c2: &Circle) -> StraightLine {
@@ -179,12 +224,16 @@ pub fn get_circles_intersection_as_straight_line(c1: &Circle, c2: &Circle) -> St
     StraightLine { a, b, c }
 }
 
-pub fn find_projection(l: &StraightLine, p: Pos2) -> Pos2 {
+pub fn find_projection(
+    l: &StraightLine,
+    p: Point,
+    tolerance: &Tolerance,
+) -> Result<Point, GeometryError> {
     let ort_l: StraightLine = orthoganalize(l, p);
-    find_intersection(l, &ort_l)
+    find_intersection(l, &ort_l, tolerance)
 }
 
-pub fn orthoganalize(l: &StraightLine, p: Pos2) -> StraightLine {
+pub fn orthoganalize(l: &StraightLine, p: Point) -> StraightLine {
     let a = l.b;
     let b = -l.a;
     let c = -l.b * p.x + l.a * p.y;
@@ -192,9 +241,9 @@ pub fn orthoganalize(l: &StraightLine, p: Pos2) -> StraightLine {
     StraightLine { a, b, c }
 }
 
-pub fn mid_point(s: &Segment) -> Pos2 {
+pub fn mid_point(s: &Segment) -> Point {
     let x = (s.1.x + s.0.x) / 2.0;
     let y = (s.1.y + s.0.y) / 2.0;
 
-    Pos2 { x, y }
+    Point { x, y }
 }
@@ -0,0 +1,88 @@
+use crate::{
+    models::{
+        circle::Circle, input::Input, inversion::Inversion, segment::Segment, tolerance::Tolerance,
+    },
+    services,
+};
+
+// Inverting about a point two input circles share turns both into lines, which leaves an
+// easier problem with no touching or crossing circles. Solutions through the shared point
+// come back as lines and are mapped back on their own.
+pub fn solve_apollonius_inversion(
+    circles: &[Circle; 3],
+    tolerance: &Tolerance,
+) -> Option<Vec<Circle>> {
+    if circles.iter().any(|circle| circle.is_point(tolerance)) {
+        return None;
+    }
+
+    let ((i, j, k), shared) =
+        [(0, 1, 2), (1, 2, 0), (2, 0, 1)]
+            .into_iter()
+            .find_map(|(i, j, k)| {
+                services::calc::get_circles_intersection(&circles[i], &circles[j], tolerance)
+                    .ok()
+                    .map(|shared| ((i, j, k), shared))
+            })?;
+    // A touching point comes out as two points either side of the line of centers, and inverting
+    // about either one would tilt the two lines towards each other
+    let distance = circles[i].center.distance(circles[j].center);
+    let touching = tolerance.is_equal(distance, circles[i].radius + circles[j].radius)
+        || tolerance.is_equal(distance, (circles[i].radius - circles[j].radius).abs());
+    let center = if touching {
+        shared.0 + (shared.1 - shared.0) * 0.5
+    } else {
+        shared.0
+    };
+    let inversion = Inversion {
+        center,
+        power: circles[i].radius * circles[j].radius,
+    };
+
+    let inverted: [Input; 3] = [
+        inversion.invert_circle(&circles[i], tolerance)?,
+        inversion.invert_circle(&circles[j], tolerance)?,
+        inversion.invert_circle(&circles[k], tolerance)?,
+    ];
+
+    let mut solutions: Vec<Circle> =
+        services::algebraic::solve_apollonius_algebraic(&inverted, tolerance)
+            .iter()
+            .filter_map(
+                |solution| match inversion.invert_circle(solution, tolerance)? {
+                    Input::Circle(circle) => Some(circle),
+                    Input::Line(_) => None,
+                },
+            )
+            .collect();
+    solutions.extend(
+        get_tangent_lines(&inverted, tolerance)
+            .iter()
+            .filter_map(|line| inversion.invert_line(line, tolerance)),
+    );
+
+    Some(solutions)
+}
+
+// Lines touching the inverted problem, which only exist when the shared point was a touching
+// point: the two circles then turn into parallel lines and any line parallel to them that
+// touches the third circle will do, the two inputs themselves when it touches both. Crossing
+// lines have no line touching both.
+fn get_tangent_lines(inverted: &[Input; 3], tolerance: &Tolerance) -> Vec<Segment> {
+    let [Input::Line(l1), Input::Line(l2), Input::Circle(circle)] = inverted else {
+        return vec![];
+    };
+    let (d1, d2) = (l1.1 - l1.0, l2.1 - l2.0);
+    if !tolerance.is_zero(d1.cross(d2), d1.length() * d2.length()) {
+        return vec![];
+    }
+
+    let along = d1 * (1.0 / d1.length());
+    [1.0, -1.0]
+        .iter()
+        .map(|side| {
+            let touch = circle.center + along.rot90() * (side * circle.radius);
+            Segment(touch, touch + along)
+        })
+        .collect()
+}
@@ -1,3 +1,4 @@
+pub mod algebraic;
 pub mod calc;
-pub mod draw;
-pub mod theme;
+pub mod inversion;
+pub mod solver;
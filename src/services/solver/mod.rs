@@ -0,0 +1,181 @@
+use crate::models::point::Point;
+
+use crate::{
+    models::{
+        apollonius_pair::ApolloniusPair,
+        circle::Circle,
+        configuration::{Configuration, ConfigurationClass},
+        construction::Construction,
+        geometry_error::GeometryError,
+        homothetic_set::HomotheticSet,
+        input::Input,
+        inverse_pole_set::InversePoleSet,
+        solution::Solution,
+        solutions::Solutions,
+        solver_method::SolverMethod,
+        straightline::StraightLine,
+        tangency::{Tangency, TangencySignature},
+        tolerance::Tolerance,
+    },
+    services,
+};
+
+// Which end of each inverse pole segment makes up the first circle of every pair
+const APOLLONIUS_ORDERS: [(i8, i8, i8); 4] = [(0, 0, 0), (0, 0, 1), (1, 0, 1), (0, 1, 1)];
+
+// Any mix of points, lines and circles, only three circles go through the construction
+pub fn solve(inputs: &[Input; 3], tolerance: &Tolerance) -> Solutions {
+    if let [Some(c1), Some(c2), Some(c3)] = inputs.map(|input| input.as_circle()) {
+        return solve_apollonius(&[c1, c2, c3], tolerance);
+    }
+
+    let algebraic_circles = services::algebraic::solve_apollonius_algebraic(inputs, tolerance);
+    let tangent_circles = algebraic_circles
+        .iter()
+        .map(|circle| Solution::classify(*circle, inputs))
+        .collect();
+
+    Solutions {
+        configuration: Configuration::classify(inputs, tolerance),
+        construction: None,
+        algebraic_circles,
+        tangent_circles,
+        method: SolverMethod::Algebraic,
+    }
+}
+
+pub fn solve_apollonius(circles: &[Circle; 3], tolerance: &Tolerance) -> Solutions {
+    // Homothetic centers, `order` keeps where each sorted circle came from
+    let mut order: [usize; 3] = [0, 1, 2];
+    order.sort_by(|&a, &b| circles[a].radius.total_cmp(&circles[b].radius));
+    let sorted_circles: [Circle; 3] = order.map(|idx| circles[idx]);
+    let homothetic_set: HomotheticSet = HomotheticSet::new(&sorted_circles, tolerance);
+
+    // Radical center
+    let radical_axes: [StraightLine; 2] = [
+        services::calc::get_radical_axis(circles[0], circles[1]),
+        services::calc::get_radical_axis(circles[1], circles[2]),
+    ];
+    let radical_center: Result<Point, GeometryError> =
+        services::calc::find_intersection(&radical_axes[0], &radical_axes[1], tolerance);
+
+    // Inverse poles sets, the first homothetic axis is missing when all radii are equal.
+    // Points have no polars, so those inputs are left to the algebraic solver.
+    let same_radius = same_radius(circles, tolerance);
+    let has_point = circles.iter().any(|circle| circle.is_point(tolerance));
+    let inverse_pole_sets: [Result<InversePoleSet, GeometryError>; 4] =
+        std::array::from_fn(|idx| {
+            let radical_center = radical_center?;
+            if has_point {
+                Err(GeometryError::PointInput)
+            } else if idx == 0 && same_radius {
+                InversePoleSet::new_special(&sorted_circles, radical_center, tolerance)
+            } else {
+                InversePoleSet::new(
+                    homothetic_set.lines[idx],
+                    &sorted_circles,
+                    radical_center,
+                    tolerance,
+                )
+            }
+        });
+
+    // Apollonius pairs
+    let apollonius_pairs: [ApolloniusPair; 4] = std::array::from_fn(|idx| {
+        ApolloniusPair::get_apollonius_circles(
+            &inverse_pole_sets[idx],
+            APOLLONIUS_ORDERS[idx],
+            tolerance,
+        )
+    });
+
+    let construction = Construction {
+        homothetic_axes: to_pair_order(homothetic_set.lines, order),
+        homothetic_set,
+        radical_center,
+        inverse_pole_sets: to_pair_order(inverse_pole_sets, order),
+        apollonius_pairs: to_pair_order(apollonius_pairs, order),
+    };
+    let inputs = circles.map(Input::Circle);
+    let configuration = Configuration::classify(&inputs, tolerance);
+    let algebraic_circles = services::algebraic::solve_apollonius_algebraic(&inputs, tolerance);
+
+    // The construction always yields four pairs, so it only stands when that is the right count
+    // and every circle actually touches the inputs, otherwise the closed-form circles take over
+    let fits = |found: &[Solution]| {
+        configuration
+            .expected_count()
+            .is_none_or(|count| count == found.len())
+            && found.iter().all(|solution| solution.is_tangent(tolerance))
+    };
+    let classify = |found: Vec<Circle>| -> Vec<Solution> {
+        found
+            .into_iter()
+            .map(|circle| Solution::classify(circle, &inputs))
+            .collect()
+    };
+    let from_construction: Vec<Solution> = classify(construction.circles().collect());
+    // Homothetic axes and inverse poles are unstable once two circles touch or cross
+    let well_conditioned = !matches!(
+        configuration.class,
+        ConfigurationClass::Tangent | ConfigurationClass::Intersecting(_)
+    );
+    let construction_fits =
+        well_conditioned && !construction.is_degenerate() && fits(&from_construction);
+    let (method, tangent_circles) = if construction_fits {
+        (SolverMethod::Construction, from_construction)
+    } else {
+        // The inversion is held to the same standard
+        match services::inversion::solve_apollonius_inversion(circles, tolerance)
+            .map(classify)
+            .filter(|found| fits(found))
+        {
+            Some(found) => (SolverMethod::Inversion, found),
+            None => (SolverMethod::Algebraic, classify(algebraic_circles.clone())),
+        }
+    };
+
+    Solutions {
+        configuration,
+        construction: Some(construction),
+        algebraic_circles,
+        tangent_circles,
+        method,
+    }
+}
+
+// Axis 0 of the sorted circles holds the three external centers, its solutions touch all
+// circles the same way. Every other axis holds one external center and leaves the remaining
+// sorted circle, 2, 1 and 0 in turn, touched the other way.
+fn pair_index(axis: usize, order: [usize; 3]) -> usize {
+    let odd_one = match axis {
+        0 => return 0,
+        1 => order[2],
+        2 => order[1],
+        _ => order[0],
+    };
+
+    TangencySignature(std::array::from_fn(|idx| {
+        if idx == odd_one {
+            Tangency::Internal
+        } else {
+            Tangency::External
+        }
+    }))
+    .pair_index()
+}
+
+fn to_pair_order<T>(by_axis: [T; 4], order: [usize; 3]) -> [T; 4] {
+    let mut by_pair: [Option<T>; 4] = [None, None, None, None];
+    for (axis, item) in by_axis.into_iter().enumerate() {
+        by_pair[pair_index(axis, order)] = Some(item);
+    }
+
+    by_pair.map(Option::unwrap)
+}
+
+// Has to agree with the homothetic set, which drops the external centers of equal radii
+fn same_radius(circles: &[Circle; 3], tolerance: &Tolerance) -> bool {
+    tolerance.is_equal(circles[0].radius, circles[1].radius)
+        && tolerance.is_equal(circles[1].radius, circles[2].radius)
+}
@@ -16,6 +16,13 @@ static COLORS_MAP: Lazy<HashMap<ColorItemNames, (Color32, Color32)>> = Lazy::new
             Color32::WHITE.gamma_multiply(0.3),
         ),
     );
+    colors_mapping.insert(
+        ColorItemNames::UnselectedInputs,
+        (
+            Color32::BLACK.gamma_multiply(0.08),
+            Color32::WHITE.gamma_multiply(0.12),
+        ),
+    );
     colors_mapping.insert(
         ColorItemNames::HomotheticCenters,
         (Color32::GRAY, Color32::GRAY),
@@ -50,6 +57,27 @@ static COLORS_MAP: Lazy<HashMap<ColorItemNames, (Color32, Color32)>> = Lazy::new
         ),
     );
 
+    colors_mapping.insert(
+        ColorItemNames::Algebraic,
+        (
+            Color32::from_rgb(220, 60, 60),
+            Color32::from_rgb(240, 90, 90),
+        ),
+    );
+
+    colors_mapping.insert(
+        ColorItemNames::Flagged,
+        (Color32::from_rgb(255, 0, 0), Color32::from_rgb(255, 40, 40)),
+    );
+
+    colors_mapping.insert(
+        ColorItemNames::Selected,
+        (
+            Color32::from_rgb(0, 120, 215),
+            Color32::from_rgb(90, 170, 255),
+        ),
+    );
+
     colors_mapping
 });
 
@@ -0,0 +1,34 @@
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_apollonius-cli"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn prints_every_solution_as_csv() {
+    let output = run(&["-o", "csv", "50,50,35", "-60,10,50", "40,-30,20"]);
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let rows: Vec<&str> = stdout.lines().skip(1).collect();
+    assert_eq!(rows.len(), 8);
+    assert!(rows.iter().all(|row| row.ends_with(",true")));
+}
+
+#[test]
+fn rejects_degenerate_inputs() {
+    assert_eq!(run(&["0,0,1", "0,0,1", "0,8,1"]).status.code(), Some(2));
+    assert_eq!(run(&["0,0,1", "5,0,1", "1,1,1,1"]).status.code(), Some(2));
+    assert_eq!(
+        run(&["0,0,1,0", "0,1,1,1", "0,2,1,2"]).status.code(),
+        Some(2)
+    );
+    assert_eq!(
+        run(&["0,0,1,0", "0,0,0,1", "0,0,1,1"]).status.code(),
+        Some(2)
+    );
+    assert_eq!(run(&["0,0,1", "5,0,1"]).status.code(), Some(1));
+}
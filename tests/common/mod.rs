@@ -0,0 +1,36 @@
+// Fixtures shared by the test binaries, each of them only uses part of it
+#![allow(dead_code)]
+
+use apollonius::{Circle, models::point::Point};
+use proptest::prelude::*;
+
+// Gap kept between the circles so a configuration cannot sit right on a class boundary
+pub const MARGIN: f64 = 5.0;
+
+pub fn circle(x: f64, y: f64, radius: f64) -> Circle {
+    Circle {
+        center: Point { x, y },
+        radius,
+    }
+}
+
+pub fn any_circle() -> impl Strategy<Value = Circle> {
+    (-150.0..150.0, -150.0..150.0, 5.0..100.0).prop_map(|(x, y, r)| circle(x, y, r))
+}
+
+pub fn pairs(circles: &[Circle; 3]) -> [(Circle, Circle); 3] {
+    [
+        (circles[0], circles[1]),
+        (circles[1], circles[2]),
+        (circles[2], circles[0]),
+    ]
+}
+
+// No circle touches or contains another
+pub fn disjoint() -> impl Strategy<Value = [Circle; 3]> {
+    [any_circle(), any_circle(), any_circle()].prop_filter("circles overlap", |circles| {
+        pairs(circles)
+            .iter()
+            .all(|(c1, c2)| c1.center.distance(c2.center) > c1.radius + c2.radius + MARGIN)
+    })
+}
@@ -0,0 +1,26 @@
+use apollonius::{
+    Circle, Input,
+    models::{point::Point, segment::Segment},
+};
+use proptest::prelude::*;
+
+fn any_point() -> impl Strategy<Value = Point> {
+    (-1e3..1e3, -1e3..1e3).prop_map(|(x, y)| Point { x, y })
+}
+
+fn any_input() -> impl Strategy<Value = Input> {
+    prop_oneof![
+        (any_point(), 0.0..200.0)
+            .prop_map(|(center, radius)| Input::Circle(Circle { center, radius })),
+        (any_point(), any_point()).prop_map(|(p1, p2)| Input::Line(Segment(p1, p2))),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn inputs_survive_a_json_round_trip(input in any_input()) {
+        let json = serde_json::to_string(&input).unwrap();
+        let read: Input = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(read, input);
+    }
+}
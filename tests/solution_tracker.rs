@@ -0,0 +1,56 @@
+use apollonius::{
+    Circle, Input, SolutionTracker, Tolerance,
+    models::{point::Point, solution::Solution},
+};
+use proptest::prelude::*;
+
+use common::disjoint;
+
+mod common;
+
+const MAX_RADIUS: f64 = 5_000.0;
+
+fn solve(circles: &[Circle; 3]) -> Vec<Solution> {
+    apollonius::solve(&circles.map(Input::Circle), &Tolerance::default()).tangent_circles
+}
+
+proptest! {
+    #[test]
+    fn branches_keep_their_ids_through_small_steps(
+        circles in disjoint(),
+        dx in -0.5..0.5,
+        dy in -0.5..0.5,
+        reversed in any::<bool>(),
+    ) {
+        let mut tracker = SolutionTracker::default();
+        let before: Vec<(usize, Circle)> = tracker
+            .update(&solve(&circles))
+            .iter()
+            .map(|tracked| (tracked.id, tracked.solution.circle))
+            .collect();
+
+        let mut moved = circles;
+        moved[0].center = Point {
+            x: moved[0].center.x + dx,
+            y: moved[0].center.y + dy,
+        };
+        // The order solutions come out in must not matter
+        let mut next = solve(&moved);
+        if reversed {
+            next.reverse();
+        }
+        // A branch passing through a straight line comes back touching the other way
+        prop_assume!(next.iter().all(|solution| solution.circle.radius < MAX_RADIUS));
+        prop_assume!(before.iter().all(|(_, circle)| circle.radius < MAX_RADIUS));
+        let after = tracker.update(&next);
+
+        prop_assert_eq!(before.len(), after.len());
+        for tracked in after {
+            let previous = before.iter().find(|(id, _)| *id == tracked.id);
+            prop_assert!(previous.is_some(), "branch {} is new", tracked.id);
+            let (_, previous) = previous.unwrap();
+            let signature = Solution::classify(*previous, &circles.map(Input::Circle)).signature;
+            prop_assert_eq!(signature, tracked.solution.signature);
+        }
+    }
+}
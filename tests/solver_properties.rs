@@ -0,0 +1,449 @@
+use apollonius::{
+    Circle, ConfigurationClass, Input, Solutions, SolverMethod, Tolerance,
+    models::{point::Point, solution::Solution},
+};
+use proptest::prelude::*;
+
+use common::{MARGIN, any_circle, circle, disjoint, pairs};
+
+mod common;
+
+// Looser than the default so the long way round through the construction still passes
+const TEST_TOLERANCE: Tolerance = Tolerance {
+    absolute: 1e-6,
+    relative: 1e-8,
+};
+
+fn solve(circles: &[Circle; 3]) -> Solutions {
+    apollonius::solve(&circles.map(Input::Circle), &TEST_TOLERANCE)
+}
+
+// Every pair crosses at two points
+fn intersecting() -> impl Strategy<Value = [Circle; 3]> {
+    [any_circle(), any_circle(), any_circle()].prop_filter("circles do not cross", |circles| {
+        pairs(circles).iter().all(|(c1, c2)| {
+            let distance = c1.center.distance(c2.center);
+            distance > (c1.radius - c2.radius).abs() + MARGIN
+                && distance < c1.radius + c2.radius - MARGIN
+        })
+    })
+}
+
+// The first two circles cross at a small angle, just short of touching inside or outside,
+// the third one keeps clear of touching either
+fn shallow_crossing() -> impl Strategy<Value = [Circle; 3]> {
+    (
+        any_circle(),
+        5.0..100.0,
+        0.01..MARGIN,
+        any::<bool>(),
+        0.0..std::f64::consts::TAU,
+        any_circle(),
+    )
+        .prop_map(
+            |(c1, r2, gap, outside, angle, c3): (Circle, f64, f64, bool, f64, Circle)| {
+                let distance = if outside {
+                    c1.radius + r2 - gap
+                } else {
+                    (c1.radius - r2).abs() + gap
+                };
+                let c2 = circle(
+                    c1.center.x + distance * angle.cos(),
+                    c1.center.y + distance * angle.sin(),
+                    r2,
+                );
+                [c1, c2, c3]
+            },
+        )
+        .prop_filter("third circle touches", |circles| {
+            [circles[0], circles[1]].iter().all(|c| {
+                let distance = c.center.distance(circles[2].center);
+                (distance - c.radius - circles[2].radius).abs() > MARGIN
+                    && (distance - (c.radius - circles[2].radius).abs()).abs() > MARGIN
+            })
+        })
+}
+
+// A point strictly inside `outer` with room for a circle of `radius` around it
+fn inside(outer: Circle, radius: f64, offset: f64, angle: f64) -> Circle {
+    let room = outer.radius - radius - MARGIN;
+    circle(
+        outer.center.x + room * offset * angle.cos(),
+        outer.center.y + room * offset * angle.sin(),
+        radius,
+    )
+}
+
+// Two separate circles inside a third one
+fn enclosed() -> impl Strategy<Value = [Circle; 3]> {
+    (
+        any_circle().prop_map(|c| circle(c.center.x, c.center.y, c.radius + 100.0)),
+        (5.0..40.0, 0.0..1.0, 0.0..std::f64::consts::TAU),
+        (5.0..40.0, 0.0..1.0, 0.0..std::f64::consts::TAU),
+    )
+        .prop_map(|(outer, (r1, o1, a1), (r2, o2, a2))| {
+            [outer, inside(outer, r1, o1, a1), inside(outer, r2, o2, a2)]
+        })
+        .prop_filter("inner circles overlap", |circles| {
+            circles[1].center.distance(circles[2].center)
+                > circles[1].radius + circles[2].radius + MARGIN
+        })
+}
+
+// Each circle inside the next one, nothing can touch all three
+fn nested() -> impl Strategy<Value = [Circle; 3]> {
+    (
+        any_circle().prop_map(|c| circle(c.center.x, c.center.y, c.radius + 100.0)),
+        (0.3_f64..0.8, 0.0..1.0, 0.0..std::f64::consts::TAU),
+        (0.3_f64..0.8, 0.0..1.0, 0.0..std::f64::consts::TAU),
+    )
+        .prop_map(|(outer, (f1, o1, a1), (f2, o2, a2))| {
+            let middle = inside(outer, outer.radius * f1, o1, a1);
+            let inner = inside(
+                middle,
+                (middle.radius * f2).min(middle.radius - 2.0 * MARGIN),
+                o2,
+                a2,
+            );
+            [inner, outer, middle]
+        })
+}
+
+// Any three circles, as long as no pair is close to touching
+fn general_position() -> impl Strategy<Value = [Circle; 3]> {
+    [any_circle(), any_circle(), any_circle()].prop_filter("circles nearly touch", |circles| {
+        pairs(circles).iter().all(|(c1, c2)| {
+            let distance = c1.center.distance(c2.center);
+            (distance - (c1.radius + c2.radius)).abs() > MARGIN
+                && (distance - (c1.radius - c2.radius).abs()).abs() > MARGIN
+        })
+    })
+}
+
+// How near the third circle comes to touching one of the lines that touch the first two. A
+// line n . p = d touches circle i when n . ci - d = si * ri, the first circle taking si = 1.
+fn common_tangent_clearance(circles: &[Circle; 3]) -> f64 {
+    let offset = circles[1].center - circles[0].center;
+    let along = offset * (1.0 / offset.length());
+    let mut clearance = f64::INFINITY;
+    for sign in [1.0, -1.0] {
+        // Touching circles share the line through their touching point, at k = -1 give or take
+        // rounding
+        let k = (sign * circles[1].radius - circles[0].radius) / offset.length();
+        if k.abs() > 1.0 + 1e-9 {
+            continue;
+        }
+        for side in [1.0, -1.0] {
+            let normal = along * k + along.rot90() * (side * (1.0 - k * k).max(0.0).sqrt());
+            let distance = normal.dot(circles[2].center - circles[0].center) + circles[0].radius;
+            clearance = clearance.min((distance.abs() - circles[2].radius).abs());
+        }
+    }
+    clearance
+}
+
+// The first two circles touch from outside, the third one keeps clear of both and of the
+// lines touching both, where the far away solutions run off
+fn touching() -> impl Strategy<Value = [Circle; 3]> {
+    (
+        any_circle(),
+        5.0..100.0,
+        0.0..std::f64::consts::TAU,
+        any_circle(),
+    )
+        .prop_map(|(c1, r2, angle, c3)| {
+            let distance = c1.radius + r2;
+            let c2 = circle(
+                c1.center.x + distance * angle.cos(),
+                c1.center.y + distance * angle.sin(),
+                r2,
+            );
+            [c1, c2, c3]
+        })
+        .prop_filter("third circle overlaps", |circles| {
+            [circles[0], circles[1]].iter().all(|c| {
+                c.center.distance(circles[2].center) > c.radius + circles[2].radius + MARGIN
+            })
+        })
+        .prop_filter("a line nearly touches all three", |circles| {
+            common_tangent_clearance(circles) > MARGIN
+        })
+}
+
+// A point on the rim of the first circle, and a second point clear of it
+fn point_on_circle() -> impl Strategy<Value = [Circle; 3]> {
+    (any_circle(), 0.0..std::f64::consts::TAU, any_circle())
+        .prop_map(|(c1, angle, far)| {
+            let on_rim = circle(
+                c1.center.x + c1.radius * angle.cos(),
+                c1.center.y + c1.radius * angle.sin(),
+                0.0,
+            );
+            [c1, on_rim, circle(far.center.x, far.center.y, 0.0)]
+        })
+        .prop_filter("second point too close", |circles| {
+            circles[0].center.distance(circles[2].center) > circles[0].radius + MARGIN
+        })
+}
+
+// Centers evenly spaced on a line with radii in arithmetic progression, two lines touch all
+fn common_tangent() -> impl Strategy<Value = [Circle; 3]> {
+    (
+        any_circle(),
+        0.0..std::f64::consts::TAU,
+        -20.0..20.0_f64,
+        0.0..50.0,
+    )
+        .prop_map(|(c1, angle, growth, gap)| {
+            let step = 2.0 * c1.radius + growth.abs() * 3.0 + gap + MARGIN;
+            std::array::from_fn(|idx| {
+                let offset = step * idx as f64;
+                circle(
+                    c1.center.x + offset * angle.cos(),
+                    c1.center.y + offset * angle.sin(),
+                    c1.radius + growth * idx as f64,
+                )
+            })
+        })
+        .prop_filter("radius not positive", |circles| {
+            circles.iter().all(|c| c.radius > 1.0)
+        })
+}
+
+fn any_configuration() -> impl Strategy<Value = [Circle; 3]> {
+    prop_oneof![disjoint(), intersecting(), enclosed()]
+}
+
+fn map_circle(c: Circle, map: impl Fn(Point) -> Point, scale: f64) -> Circle {
+    Circle {
+        center: map(c.center),
+        radius: c.radius * scale,
+    }
+}
+
+// Both solution sets have to be the same circles, in whatever order
+fn assert_same_solutions(expected: &[Circle], actual: &[Solution]) -> Result<(), TestCaseError> {
+    prop_assert_eq!(expected.len(), actual.len());
+    for circle in expected {
+        prop_assert!(
+            actual.iter().any(|solution| {
+                let scale = circle.radius.max(solution.circle.radius) * 1e-6;
+                solution.circle.center.distance(circle.center) < scale
+                    && (solution.circle.radius - circle.radius).abs() < scale
+            }),
+            "{:?} has no match in {:?}",
+            circle,
+            actual
+        );
+    }
+    Ok(())
+}
+
+// The circles found have to be the closed-form circles that touch all three inputs
+fn assert_matches_closed_form(
+    circles: &[Circle; 3],
+    solutions: &Solutions,
+) -> Result<(), TestCaseError> {
+    let inputs = circles.map(Input::Circle);
+    let expected: Vec<Circle> = solutions
+        .algebraic_circles
+        .iter()
+        .copied()
+        .filter(|circle| Solution::classify(*circle, &inputs).is_tangent(&TEST_TOLERANCE))
+        .collect();
+    assert_same_solutions(&expected, &solutions.tangent_circles)
+}
+
+fn assert_invariant(
+    circles: [Circle; 3],
+    map: impl Fn(Point) -> Point + Copy,
+    scale: f64,
+) -> Result<(), TestCaseError> {
+    let expected: Vec<Circle> = solve(&circles)
+        .tangent_circles
+        .iter()
+        .map(|solution| map_circle(solution.circle, map, scale))
+        .collect();
+    let mapped = solve(&circles.map(|c| map_circle(c, map, scale)));
+    assert_same_solutions(&expected, &mapped.tangent_circles)
+}
+
+proptest! {
+    #[test]
+    fn solutions_are_tangent_to_every_input(circles in any_configuration()) {
+        for solution in solve(&circles).tangent_circles {
+            prop_assert!(
+                solution.is_tangent(&TEST_TOLERANCE),
+                "{:?} misses an input by {}",
+                solution.circle,
+                solution.residual
+            );
+        }
+    }
+
+    #[test]
+    fn disjoint_circles_have_eight_solutions(circles in disjoint()) {
+        let solutions = solve(&circles);
+        prop_assert_eq!(solutions.configuration.class, ConfigurationClass::Disjoint);
+        prop_assert_eq!(solutions.configuration.expected_count(), Some(8));
+        prop_assert_eq!(solutions.tangent_circles.len(), 8);
+    }
+
+    #[test]
+    fn intersecting_circles_have_eight_solutions(circles in intersecting()) {
+        let solutions = solve(&circles);
+        prop_assert_eq!(solutions.configuration.class, ConfigurationClass::Intersecting(3));
+        prop_assert_eq!(solutions.configuration.expected_count(), Some(8));
+        prop_assert_eq!(solutions.tangent_circles.len(), 8);
+    }
+
+    #[test]
+    fn enclosed_circles_have_eight_solutions(circles in enclosed()) {
+        let solutions = solve(&circles);
+        prop_assert_eq!(solutions.configuration.class, ConfigurationClass::Enclosed);
+        prop_assert_eq!(solutions.configuration.expected_count(), Some(8));
+        prop_assert_eq!(solutions.tangent_circles.len(), 8);
+    }
+
+    #[test]
+    fn nested_circles_have_no_solutions(circles in nested()) {
+        let solutions = solve(&circles);
+        prop_assert_eq!(solutions.configuration.class, ConfigurationClass::Separated);
+        prop_assert_eq!(solutions.configuration.expected_count(), Some(0));
+        prop_assert_eq!(solutions.tangent_circles.len(), 0);
+    }
+
+    #[test]
+    fn construction_pairs_follow_the_input_order(circles in disjoint()) {
+        let solutions = solve(&circles);
+        let inputs = circles.map(Input::Circle);
+        if let Some(construction) = solutions.construction {
+            for (idx, pair) in construction.apollonius_pairs.iter().enumerate() {
+                for circle in pair.into_iter().flatten() {
+                    let signature = Solution::classify(circle, &inputs).signature;
+                    prop_assert_eq!(signature.pair_index(), idx, "{}", signature.label());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn crossing_circles_go_through_the_inversion(circles in intersecting()) {
+        prop_assert_eq!(solve(&circles).method, SolverMethod::Inversion);
+    }
+
+    #[test]
+    fn touching_circles_are_solved_by_inversion(circles in touching()) {
+        let solutions = solve(&circles);
+        prop_assert_eq!(solutions.configuration.class, ConfigurationClass::Tangent);
+        prop_assert_eq!(solutions.method, SolverMethod::Inversion);
+        assert_matches_closed_form(&circles, &solutions)?;
+    }
+
+    #[test]
+    fn double_roots_give_a_single_solution(
+        circles in prop_oneof![touching(), point_on_circle()],
+    ) {
+        let solutions = solve(&circles).tangent_circles;
+        for (idx, solution) in solutions.iter().enumerate() {
+            for other in &solutions[idx + 1..] {
+                let scale = solution.circle.radius.max(other.circle.radius) * 1e-4;
+                prop_assert!(
+                    solution.circle.center.distance(other.circle.center) > scale
+                        || (solution.circle.radius - other.circle.radius).abs() > scale,
+                    "{:?} is found twice",
+                    solution.circle
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn common_tangent_lines_leave_no_expected_count(circles in common_tangent()) {
+        let solutions = solve(&circles);
+        prop_assert!(solutions.configuration.has_common_tangent);
+        prop_assert_eq!(solutions.configuration.expected_count(), None);
+        prop_assert_eq!(solutions.tangent_circles.len(), 6);
+    }
+
+    #[test]
+    fn solution_count_matches_the_configuration(circles in general_position()) {
+        let solutions = solve(&circles);
+        prop_assert!(
+            solutions.has_expected_count(),
+            "{:?} expects {:?} solutions, found {}",
+            solutions.configuration.class,
+            solutions.configuration.expected_count(),
+            solutions.tangent_circles.len()
+        );
+    }
+
+    #[test]
+    fn solutions_follow_translation(
+        circles in any_configuration(),
+        dx in -500.0..500.0,
+        dy in -500.0..500.0,
+    ) {
+        assert_invariant(circles, |p| Point { x: p.x + dx, y: p.y + dy }, 1.0)?;
+    }
+
+    #[test]
+    fn solutions_follow_rotation(
+        circles in any_configuration(),
+        angle in 0.0..std::f64::consts::TAU,
+    ) {
+        let (sin, cos) = angle.sin_cos();
+        assert_invariant(
+            circles,
+            |p| Point { x: p.x * cos - p.y * sin, y: p.x * sin + p.y * cos },
+            1.0,
+        )?;
+    }
+
+    #[test]
+    fn solutions_follow_scaling(circles in any_configuration(), scale in 0.1..10.0) {
+        assert_invariant(circles, |p| Point { x: p.x * scale, y: p.y * scale }, scale)?;
+    }
+}
+
+// Crossing at a small angle is rare even among crossing circles, it takes more cases to hit
+proptest! {
+    #![proptest_config(ProptestConfig {
+        cases: 2000,
+        max_local_rejects: 1 << 20,
+        ..ProptestConfig::default()
+    })]
+
+    #[test]
+    fn crossing_circles_match_the_closed_form(
+        circles in prop_oneof![intersecting(), shallow_crossing()],
+    ) {
+        assert_matches_closed_form(&circles, &solve(&circles))?;
+    }
+}
+
+// Two of the inputs cross at a small angle, so the lines they invert to are almost parallel
+// and the roots of the inverted problem sit close together without being double
+#[test]
+fn close_roots_stay_apart() {
+    let inputs = [
+        circle(-13.9666, 33.8911, 93.7532),
+        circle(-113.811, -130.4677, 20.9959),
+        circle(-17.2554, 62.4285, 65.1537),
+    ]
+    .map(Input::Circle);
+    let solutions = apollonius::solve(&inputs, &Tolerance::default());
+    assert_eq!(
+        solutions.configuration.class,
+        ConfigurationClass::Intersecting(1)
+    );
+    assert_eq!(solutions.tangent_circles.len(), 4);
+    for solution in solutions.tangent_circles {
+        assert!(
+            solution.is_tangent(&Tolerance::default()),
+            "{:?} misses an input by {}",
+            solution.circle,
+            solution.residual
+        );
+    }
+}